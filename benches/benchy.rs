@@ -1,10 +1,12 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use shogi_piece_values::{simulate_n, simulate_n_par};
+use shogi_piece_values::{simulate_n, simulate_n_par, Board, Uniform};
 
 pub fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("Simulate 100", |b| b.iter(|| simulate_n(100)));
+    c.bench_function("Simulate 100", |b| {
+        b.iter(|| simulate_n(Board::TAIKYOKU, &Uniform, 100))
+    });
     c.bench_function("Simulate 100 (parallel)", |b| {
-        b.iter(|| simulate_n_par(100))
+        b.iter(|| simulate_n_par(Board::TAIKYOKU, &Uniform, 100))
     });
 }
 
@@ -0,0 +1,418 @@
+use std::fmt::Display;
+
+use crate::face::{Direction, Move};
+
+// Betza notation describes moves from the moving side's point of view, so
+// north is forward (`f`) and east is right (`r`).
+const DIRECTIONS: [Direction; 8] = [
+    Direction::North,
+    Direction::Northeast,
+    Direction::East,
+    Direction::Southeast,
+    Direction::South,
+    Direction::Southwest,
+    Direction::West,
+    Direction::Northwest,
+];
+
+// Sets of directions, one bit per entry of `DIRECTIONS`.
+const NORTH: u8 = 1 << 0;
+const NORTHEAST: u8 = 1 << 1;
+const EAST: u8 = 1 << 2;
+const SOUTHEAST: u8 = 1 << 3;
+const SOUTH: u8 = 1 << 4;
+const SOUTHWEST: u8 = 1 << 5;
+const WEST: u8 = 1 << 6;
+const NORTHWEST: u8 = 1 << 7;
+
+const ORTHOGONAL: u8 = NORTH | EAST | SOUTH | WEST;
+const DIAGONAL: u8 = NORTHEAST | SOUTHEAST | SOUTHWEST | NORTHWEST;
+
+fn bit(direction: &Direction) -> u8 {
+    1 << DIRECTIONS.iter().position(|d| d == direction).unwrap()
+}
+
+#[derive(Debug)]
+pub enum BetzaError {
+    Syntax {
+        position: usize,
+        message: String,
+    },
+    Unsupported {
+        construct: String,
+        reason: &'static str,
+    },
+}
+
+impl Display for BetzaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BetzaError::Syntax { position, message } => {
+                write!(f, "invalid Betza at position {position}: {message}")
+            }
+            BetzaError::Unsupported { construct, reason } => {
+                write!(f, "`{construct}` cannot be expressed as a Move: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BetzaError {}
+
+// Parses Betza notation with the XBetza direction modifiers (`f`, `b`, `l`,
+// `r`, `s`, `v` and the diagonal pairs such as `fr`) into moves. Modifiers
+// that restrict what a move may do (`m`, `c`, `a`, `j`, ...) have no `Move`
+// equivalent and are reported rather than dropped.
+pub fn parse_betza(notation: &str) -> Result<Vec<Move>, BetzaError> {
+    let chars: Vec<char> = notation.chars().collect();
+    let mut moves = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && chars[i].is_ascii_lowercase() {
+            i += 1;
+        }
+        let modifiers: String = chars[start..i].iter().collect();
+
+        let atom = match chars.get(i) {
+            Some(&c) if c.is_ascii_uppercase() => c,
+            Some('(') => {
+                return Err(unsupported(
+                    &chars[start..],
+                    "coordinate leaps are not supported",
+                ))
+            }
+            Some(&c) => {
+                return Err(BetzaError::Syntax {
+                    position: i,
+                    message: format!("unexpected `{c}`"),
+                })
+            }
+            None => {
+                return Err(BetzaError::Syntax {
+                    position: i,
+                    message: "expected a piece letter".to_string(),
+                })
+            }
+        };
+        i += 1;
+
+        // WW and FF are the rider forms of W and F.
+        let doubled = chars.get(i) == Some(&atom);
+        if doubled {
+            i += 1;
+        }
+
+        let count_start = i;
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+        let count: Option<u32> = if count_start < i {
+            let digits: String = chars[count_start..i].iter().collect();
+            Some(digits.parse().map_err(|_| BetzaError::Syntax {
+                position: count_start,
+                message: format!("invalid count `{digits}`"),
+            })?)
+        } else {
+            None
+        };
+
+        let group = &chars[start..i];
+        if let Some(m) = modifiers
+            .chars()
+            .find(|m| !matches!(m, 'f' | 'b' | 'l' | 'r' | 's' | 'v'))
+        {
+            return Err(unsupported(group, modifier_reason(m)));
+        }
+        moves.extend(parse_group(group, &modifiers, atom, doubled, count)?);
+    }
+
+    Ok(moves)
+}
+
+fn unsupported(group: &[char], reason: &'static str) -> BetzaError {
+    BetzaError::Unsupported {
+        construct: group.iter().collect(),
+        reason,
+    }
+}
+
+fn modifier_reason(modifier: char) -> &'static str {
+    match modifier {
+        'm' | 'c' => "moves cannot be restricted to non-captures or captures",
+        'a' => "multi-leg moves are not supported",
+        'n' | 'j' => "moves cannot be made blockable or jump-only",
+        'p' | 'g' => "hopping moves are not supported",
+        'i' => "initial moves are not supported",
+        _ => "unknown modifier",
+    }
+}
+
+fn parse_group(
+    group: &[char],
+    modifiers: &str,
+    atom: char,
+    doubled: bool,
+    count: Option<u32>,
+) -> Result<Vec<Move>, BetzaError> {
+    let (atom, doubled) = match (atom, doubled) {
+        ('W', true) => ('R', false),
+        ('F', true) => ('B', false),
+        _ => (atom, doubled),
+    };
+    if doubled {
+        return Err(unsupported(group, "only W and F can be doubled"));
+    }
+
+    if atom == 'N' {
+        if count.is_some() {
+            return Err(unsupported(group, "nightriders are not supported"));
+        }
+        return match modifiers {
+            "ff" => Ok(vec![Move::KnightForward]),
+            "bb" => Ok(vec![Move::KnightBackward]),
+            "ffbb" | "bbff" => Ok(vec![Move::KnightForward, Move::KnightBackward]),
+            _ => Err(unsupported(
+                group,
+                "only the narrow forward (ffN) and backward (bbN) knight moves exist",
+            )),
+        };
+    }
+
+    // A count of 0 means an unlimited rider, as in W0 for R.
+    let (directions, slide, leap) = match atom {
+        'W' => (ORTHOGONAL, count, 1),
+        'F' => (DIAGONAL, count, 1),
+        'K' => (ORTHOGONAL | DIAGONAL, count, 1),
+        'R' => (ORTHOGONAL, count.or(Some(0)), 1),
+        'B' => (DIAGONAL, count.or(Some(0)), 1),
+        'Q' => (ORTHOGONAL | DIAGONAL, count.or(Some(0)), 1),
+        'D' => (ORTHOGONAL, None, 2),
+        'A' => (DIAGONAL, None, 2),
+        'H' => (ORTHOGONAL, None, 3),
+        'G' => (DIAGONAL, None, 3),
+        _ => return Err(unsupported(group, "unknown piece letter")),
+    };
+    if leap > 1 && count.is_some() {
+        return Err(unsupported(group, "leaper riders are not supported"));
+    }
+
+    let selected = match modifiers {
+        "" => directions,
+        _ => select(modifiers, directions),
+    };
+    let to_move = |direction: Direction| match slide {
+        Some(0) => Move::Range(direction),
+        Some(n) => Move::Step(n, direction),
+        None if leap == 1 => Move::Step(1, direction),
+        None => Move::Jump(leap, direction),
+    };
+
+    Ok(DIRECTIONS
+        .iter()
+        .filter(|&d| selected & bit(d) != 0)
+        .map(|d| to_move(d.clone()))
+        .collect())
+}
+
+// The directions picked out of `directions` by direction modifiers. On atoms
+// with diagonal moves, `fr`, `fl`, `br` and `bl` (in either order) name a
+// single diagonal; everywhere else each letter selects its own side.
+fn select(modifiers: &str, directions: u8) -> u8 {
+    let letters: Vec<char> = modifiers.chars().collect();
+    let mut selected = 0;
+    let mut i = 0;
+
+    while i < letters.len() {
+        if directions & DIAGONAL != 0 {
+            if let Some(&next) = letters.get(i + 1) {
+                if let Some(diagonal) = diagonal(letters[i], next) {
+                    selected |= diagonal;
+                    i += 2;
+                    continue;
+                }
+            }
+        }
+
+        selected |= match letters[i] {
+            'f' => NORTH | NORTHEAST | NORTHWEST,
+            'b' => SOUTH | SOUTHEAST | SOUTHWEST,
+            'l' => WEST | NORTHWEST | SOUTHWEST,
+            'r' => EAST | NORTHEAST | SOUTHEAST,
+            's' => EAST | WEST | NORTHEAST | NORTHWEST | SOUTHEAST | SOUTHWEST,
+            _ => NORTH | SOUTH | NORTHEAST | NORTHWEST | SOUTHEAST | SOUTHWEST,
+        };
+        i += 1;
+    }
+
+    selected & directions
+}
+
+fn diagonal(a: char, b: char) -> Option<u8> {
+    match (a, b) {
+        ('f', 'r') | ('r', 'f') => Some(NORTHEAST),
+        ('f', 'l') | ('l', 'f') => Some(NORTHWEST),
+        ('b', 'r') | ('r', 'b') => Some(SOUTHEAST),
+        ('b', 'l') | ('l', 'b') => Some(SOUTHWEST),
+        _ => None,
+    }
+}
+
+// A face's moves in Betza notation, together with the moves it has no
+// notation for.
+pub struct Betza {
+    pub notation: String,
+    pub inexpressible: Vec<Move>,
+}
+
+impl Display for Betza {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.notation)?;
+        if !self.inexpressible.is_empty() {
+            let moves: Vec<String> = self
+                .inexpressible
+                .iter()
+                .map(|m| format!("{m:?}"))
+                .collect();
+            write!(f, " (cannot express: {})", moves.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+// What a move does along its directions: slide up to `n` squares (`None` for
+// unlimited) or leap exactly `n` squares.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Atom {
+    Slide(Option<u32>),
+    Leap(u32),
+}
+
+pub fn to_betza(moves: &[Move]) -> Betza {
+    let mut groups: Vec<(Atom, u8)> = Vec::new();
+    let mut knights = String::new();
+    let mut inexpressible = Vec::new();
+
+    let mut add =
+        |atom: Atom, direction: &Direction| match groups.iter_mut().find(|(a, _)| *a == atom) {
+            Some((_, directions)) => *directions |= bit(direction),
+            None => groups.push((atom, bit(direction))),
+        };
+
+    for m in moves {
+        match m {
+            // Leaping to an adjacent square is the same as stepping there.
+            Move::Step(n, direction) | Move::Jump(n, direction) if *n == 1 => {
+                add(Atom::Slide(Some(1)), direction)
+            }
+            Move::Step(n, direction) if *n > 0 => add(Atom::Slide(Some(*n)), direction),
+            Move::Range(direction) => add(Atom::Slide(None), direction),
+            Move::Jump(n @ (2 | 3), direction) => add(Atom::Leap(*n), direction),
+            Move::JumpOrRange(n @ (1..=3), direction) => {
+                let leap = match n {
+                    1 => Atom::Slide(Some(1)),
+                    _ => Atom::Leap(*n),
+                };
+                add(leap, direction);
+                add(Atom::Slide(None), direction);
+            }
+            Move::KnightForward => knights += "ffN",
+            Move::KnightBackward => knights += "bbN",
+            _ => inexpressible.push(m.clone()),
+        }
+    }
+
+    let mut notation = String::new();
+    for (atom, directions) in groups {
+        let (orthogonal, diagonal) = (directions & ORTHOGONAL, directions & DIAGONAL);
+        let (w, f, k) = match atom {
+            Atom::Slide(Some(_)) => ("W", "F", "K"),
+            Atom::Slide(None) => ("R", "B", "Q"),
+            Atom::Leap(2) => ("D", "A", ""),
+            Atom::Leap(_) => ("H", "G", ""),
+        };
+        let count = match atom {
+            Atom::Slide(Some(n)) if n > 1 => n.to_string(),
+            _ => String::new(),
+        };
+
+        if !k.is_empty() && orthogonal == ORTHOGONAL && diagonal == DIAGONAL {
+            notation += &format!("{k}{count}");
+            continue;
+        }
+        if orthogonal != 0 {
+            notation += &format!("{}{w}{count}", orthogonal_modifiers(orthogonal));
+        }
+        if diagonal != 0 {
+            notation += &format!("{}{f}{count}", diagonal_modifiers(diagonal));
+        }
+    }
+    notation += &knights;
+
+    Betza {
+        notation,
+        inexpressible,
+    }
+}
+
+fn orthogonal_modifiers(directions: u8) -> String {
+    if directions == ORTHOGONAL {
+        return String::new();
+    }
+
+    let mut modifiers = String::new();
+    match (directions & NORTH != 0, directions & SOUTH != 0) {
+        (true, true) => modifiers.push('v'),
+        (true, false) => modifiers.push('f'),
+        (false, true) => modifiers.push('b'),
+        (false, false) => {}
+    }
+    match (directions & WEST != 0, directions & EAST != 0) {
+        (true, true) => modifiers.push('s'),
+        (true, false) => modifiers.push('l'),
+        (false, true) => modifiers.push('r'),
+        (false, false) => {}
+    }
+    modifiers
+}
+
+// Pairs of diagonals sharing a side get one letter, and the rest are named
+// individually. A single letter is never followed by one that would pair up
+// with it, so the result reads back unambiguously.
+fn diagonal_modifiers(directions: u8) -> String {
+    if directions == DIAGONAL {
+        return String::new();
+    }
+
+    let mut remaining = directions;
+    let mut modifiers = String::new();
+    for (letter, pair) in [
+        ('f', NORTHEAST | NORTHWEST),
+        ('b', SOUTHEAST | SOUTHWEST),
+        ('l', NORTHWEST | SOUTHWEST),
+        ('r', NORTHEAST | SOUTHEAST),
+    ] {
+        if remaining & pair == pair {
+            modifiers.push(letter);
+            remaining &= !pair;
+        }
+    }
+    for (name, diagonal) in [
+        ("fr", NORTHEAST),
+        ("fl", NORTHWEST),
+        ("br", SOUTHEAST),
+        ("bl", SOUTHWEST),
+    ] {
+        if remaining & diagonal != 0 {
+            modifiers += name;
+        }
+    }
+    modifiers
+}
@@ -0,0 +1,125 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::diagram::{Charset, Diagram};
+use crate::face::Face;
+use crate::simulate::Estimate;
+use crate::ValueEstimates;
+
+const CELL: i64 = 24;
+const MARGIN: i64 = 16;
+const MIN_WIDTH: i64 = 320;
+const HEADER: i64 = 112;
+const FOOTER: i64 = 72;
+
+// A reference card for a face: its kanji, names and promotion above its
+// movement diagram, with the value and the diagram's legend below. The value
+// is left out when it is not known and its interval when there was no
+// variance to compute one from.
+pub fn face_card(face: &Face, value: Option<&Estimate>) -> String {
+    let diagram = Diagram::new(&face.moves);
+    let radius = diagram.radius();
+    let board = (2 * radius + 1) * CELL;
+    let width = (board + 2 * MARGIN).max(MIN_WIDTH);
+    let height = HEADER + board + FOOTER;
+    let centre = width / 2;
+    let left = (width - board) / 2;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" font-family=\"sans-serif\">\n"
+    );
+    svg += &format!(
+        "  <rect x=\"0.5\" y=\"0.5\" width=\"{}\" height=\"{}\" rx=\"8\" fill=\"#fdf6e3\" stroke=\"#333\"/>\n",
+        width - 1,
+        height - 1
+    );
+
+    let kanji = format!(
+        "{}{}",
+        face.additional_kanji.as_deref().unwrap_or_default(),
+        face.kanji
+    );
+    svg += &text(centre, 44, 32, &kanji);
+    svg += &text(centre, 68, 15, &face.name);
+    svg += &text(centre, 86, 12, &face.romaji);
+    if let Some(promotion) = &face.promotion {
+        svg += &text(centre, 100, 10, &format!("Promotes to {promotion}"));
+    }
+
+    for y in -radius..=radius {
+        for x in -radius..=radius {
+            let (px, py) = (left + (x + radius) * CELL, HEADER + (y + radius) * CELL);
+            let fill = if (x, y) == (0, 0) { "#e0c080" } else { "#fff" };
+            svg += &format!(
+                "  <rect x=\"{px}\" y=\"{py}\" width=\"{CELL}\" height=\"{CELL}\" fill=\"{fill}\" stroke=\"#999\"/>\n"
+            );
+            let glyph = match diagram.glyph(x, y, Charset::Unicode) {
+                _ if (x, y) == (0, 0) => face.kanji.chars().next(),
+                glyph => glyph,
+            };
+            if let Some(glyph) = glyph {
+                svg += &text(px + CELL / 2, py + CELL * 3 / 4, 14, &glyph.to_string());
+            }
+        }
+    }
+
+    let footer = HEADER + board;
+    if let Some(estimate) = value {
+        let value = match estimate.standard_error.is_nan() {
+            true => format!("Value {:.2}", estimate.mean),
+            false => format!("Value {estimate}"),
+        };
+        svg += &text(centre, footer + 28, 16, &value);
+    }
+    svg += &text(centre, footer + 52, 11, &diagram.legend(Charset::Unicode));
+    svg += "</svg>\n";
+    svg
+}
+
+// The file a face's card is written to; the index keeps faces that share a
+// name apart and the files in table order.
+pub fn card_file_name(index: usize, face: &Face) -> String {
+    let slug: String = face
+        .name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_lowercase(),
+            false => '-',
+        })
+        .collect();
+    format!("{index:03}-{slug}.svg")
+}
+
+impl ValueEstimates {
+    // Writes a card for every face into `dir`, creating it if needed, and
+    // returns the paths written.
+    pub fn write_cards(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let ValueEstimates(estimates) = self;
+        fs::create_dir_all(dir)?;
+        estimates
+            .iter()
+            .enumerate()
+            .map(|(i, (face, estimate))| {
+                let path = dir.join(card_file_name(i + 1, face));
+                fs::write(&path, face_card(face, Some(estimate)))?;
+                Ok(path)
+            })
+            .collect()
+    }
+}
+
+fn text(x: i64, y: i64, size: i64, content: &str) -> String {
+    format!(
+        "  <text x=\"{x}\" y=\"{y}\" font-size=\"{size}\" text-anchor=\"middle\">{}</text>\n",
+        escape(content)
+    )
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::face::Face;
+
+// Faces indexed by everything a player might call them: the English name, the
+// kanji (with and without the additional kanji written before it) and the
+// romaji. Lookups try the exact spelling first and then fall back to a folded
+// one that ignores case, diacritics, spaces and punctuation, so "gyokusho"
+// finds 玉将 and "free king" finds the Free King.
+pub struct Catalogue {
+    faces: Vec<Face>,
+    exact: HashMap<String, Vec<usize>>,
+    folded: HashMap<String, Vec<usize>>,
+}
+
+impl Catalogue {
+    pub fn new(faces: &[Face]) -> Catalogue {
+        let mut exact: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut folded: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, face) in faces.iter().enumerate() {
+            for key in keys(face) {
+                let entry = exact.entry(key.clone()).or_default();
+                if !entry.contains(&i) {
+                    entry.push(i);
+                }
+                let entry = folded.entry(fold(&key)).or_default();
+                if !entry.contains(&i) {
+                    entry.push(i);
+                }
+            }
+        }
+
+        Catalogue {
+            faces: faces.to_vec(),
+            exact,
+            folded,
+        }
+    }
+
+    pub fn faces(&self) -> &[Face] {
+        &self.faces
+    }
+
+    // Every face matching the query, in catalogue order. Several faces can
+    // share a kanji or even a name, so callers decide what to do with more
+    // than one.
+    pub fn lookup(&self, query: &str) -> Vec<&Face> {
+        let indices = self
+            .exact
+            .get(query)
+            .or_else(|| self.folded.get(&fold(query)));
+        indices
+            .into_iter()
+            .flatten()
+            .map(|&i| &self.faces[i])
+            .collect()
+    }
+
+    // The one face the query names. Faces that share a name count as one,
+    // the first of them being returned.
+    pub fn find(&self, query: &str) -> Result<&Face, LookupError> {
+        let matches = self.lookup(query);
+        match matches.first() {
+            None => Err(LookupError::Unknown(query.to_string())),
+            Some(face) if matches.iter().all(|m| m.name == face.name) => Ok(face),
+            Some(_) => Err(LookupError::Ambiguous {
+                query: query.to_string(),
+                names: matches.iter().map(|m| m.name.clone()).collect(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum LookupError {
+    Unknown(String),
+    Ambiguous { query: String, names: Vec<String> },
+}
+
+impl Display for LookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookupError::Unknown(query) => write!(f, "no face named '{query}'"),
+            LookupError::Ambiguous { query, names } => {
+                write!(f, "'{query}' could be any of: {}", names.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+fn keys(face: &Face) -> Vec<String> {
+    let mut keys = vec![face.name.clone(), face.kanji.clone(), face.romaji.clone()];
+    if let Some(additional_kanji) = &face.additional_kanji {
+        keys.push(format!("{additional_kanji}{}", face.kanji));
+    }
+    keys
+}
+
+fn fold(s: &str) -> String {
+    s.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'ā' | 'á' | 'à' | 'â' | 'ä' => 'a',
+            'ē' | 'é' | 'è' | 'ê' | 'ë' => 'e',
+            'ī' | 'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ō' | 'ó' | 'ò' | 'ô' | 'ö' => 'o',
+            'ū' | 'ú' | 'ù' | 'û' | 'ü' => 'u',
+            c => c,
+        })
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
@@ -0,0 +1,135 @@
+use std::fmt::Display;
+use std::ops::ControlFlow;
+use std::path::Path;
+
+use crate::export::FormatError;
+use crate::record::SimulationRecord;
+use crate::simulate::{simulate_in_batches, Board, OccupancyModel, SimulationStatistics};
+
+// Runs `trials` trials, saving the running statistics to `path` after every
+// `interval` trials so that an interrupted run can be resumed.
+pub fn simulate_with_checkpoints(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    trials: usize,
+    seed: u64,
+    path: impl AsRef<Path>,
+    interval: usize,
+) -> Result<SimulationStatistics, FormatError> {
+    continue_with_checkpoints(
+        SimulationStatistics::new(),
+        board,
+        occupancy,
+        trials,
+        seed,
+        path,
+        interval,
+    )
+}
+
+// Continues the run saved at `path` up to `trials` trials in total. Every trial
+// has its own random stream and chunks stay aligned to multiples of CHUNK_SIZE,
+// so the resumed run ends with the same statistics as one that was never
+// interrupted: exactly when the checkpoint was made on a chunk boundary, as
+// periodic checkpoints are, and up to rounding otherwise.
+pub fn resume_from_checkpoint(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    trials: usize,
+    path: impl AsRef<Path>,
+    interval: usize,
+) -> Result<SimulationStatistics, ResumeError> {
+    let (statistics, seed) = load_checkpoint(board, occupancy, &path)?;
+    Ok(continue_with_checkpoints(
+        statistics, board, occupancy, trials, seed, path, interval,
+    )?)
+}
+
+// Checkpoints can only be continued on the board and with the occupancy model
+// they were made with.
+pub fn load_checkpoint(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    path: impl AsRef<Path>,
+) -> Result<(SimulationStatistics, u64), ResumeError> {
+    let record = SimulationRecord::load(path)?;
+    if (record.board_width, record.board_height) != (board.width(), board.height())
+        || record.occupancy_model != occupancy.to_string()
+    {
+        return Err(ResumeError::Incompatible);
+    }
+    let seed = record.seed.ok_or(ResumeError::MissingSeed)?;
+    let statistics = record.statistics.ok_or(ResumeError::MissingStatistics)?;
+    Ok((statistics, seed))
+}
+
+pub fn save_checkpoint(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    statistics: &SimulationStatistics,
+    seed: u64,
+    path: impl AsRef<Path>,
+) -> Result<(), FormatError> {
+    SimulationRecord::from_statistics(board, occupancy, statistics.clone(), Some(seed)).save(path)
+}
+
+fn continue_with_checkpoints(
+    statistics: SimulationStatistics,
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    trials: usize,
+    seed: u64,
+    path: impl AsRef<Path>,
+    interval: usize,
+) -> Result<SimulationStatistics, FormatError> {
+    let mut result = Ok(());
+    let statistics = simulate_in_batches(
+        statistics,
+        board,
+        occupancy,
+        trials,
+        seed,
+        interval,
+        |statistics| {
+            result = save_checkpoint(board, occupancy, statistics, seed, &path);
+            if result.is_ok() {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        },
+    );
+    result.map(|()| statistics)
+}
+
+#[derive(Debug)]
+pub enum ResumeError {
+    Format(FormatError),
+    Incompatible,
+    MissingSeed,
+    MissingStatistics,
+}
+
+impl Display for ResumeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResumeError::Format(e) => write!(f, "{e}"),
+            ResumeError::Incompatible => write!(
+                f,
+                "the checkpoint was made with a different board or occupancy model"
+            ),
+            ResumeError::MissingSeed => write!(f, "the checkpoint does not record its seed"),
+            ResumeError::MissingStatistics => {
+                write!(f, "the checkpoint does not include running statistics")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResumeError {}
+
+impl From<FormatError> for ResumeError {
+    fn from(e: FormatError) -> Self {
+        ResumeError::Format(e)
+    }
+}
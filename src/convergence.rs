@@ -0,0 +1,145 @@
+use std::fmt::Display;
+use std::ops::ControlFlow;
+
+use crate::assign_value_to_face;
+use crate::face::{faces, Face};
+use crate::simulate::{
+    default_batch_size, simulate_in_batches, Board, Estimate, OccupancyModel, Simulation,
+    SimulationStatistics, Uniform,
+};
+use crate::{estimate_relative_values_of_faces_with, ValueEstimates};
+
+pub struct ConvergenceCriteria {
+    pub faces: Vec<Face>,
+    pub board: Board,
+    pub occupancy: Box<dyn OccupancyModel>,
+    pub tolerance: f64,
+    pub batch_size: usize,
+    pub max_trials: usize,
+}
+
+impl Default for ConvergenceCriteria {
+    fn default() -> Self {
+        ConvergenceCriteria {
+            faces: faces(),
+            board: Board::default(),
+            occupancy: Box::new(Uniform),
+            tolerance: 0.25,
+            batch_size: default_batch_size(),
+            max_trials: 1_000_000,
+        }
+    }
+}
+
+pub struct Convergence {
+    pub statistics: SimulationStatistics,
+    pub converged: bool,
+    pub widest: Option<(Face, Estimate)>,
+}
+
+impl Convergence {
+    pub fn trials(&self) -> u64 {
+        self.statistics.count()
+    }
+}
+
+impl Display for Convergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.converged {
+            write!(f, "Converged after {} trials", self.trials())?;
+        } else {
+            write!(
+                f,
+                "Stopped after {} trials without converging",
+                self.trials()
+            )?;
+        }
+        if let Some((face, estimate)) = &self.widest {
+            write!(f, " (least certain: {}, {})", face.name, estimate)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Convergence {
+    // Judges the values of `faces` as `value` gives them, relative to
+    // `reference`.
+    pub fn assess(
+        statistics: SimulationStatistics,
+        tolerance: f64,
+        faces: &[Face],
+        value: impl Fn(&Simulation, &Face) -> f64,
+        reference: impl Fn(&Simulation) -> f64,
+    ) -> Convergence {
+        let widest = widest_estimate(&estimate_relative_values_of_faces_with(
+            faces,
+            &statistics,
+            reference,
+            value,
+        ));
+        // Nothing can be estimated from fewer than two trials.
+        let converged = statistics.count() >= 2
+            && matches!(&widest, Some((_, e)) if e.half_width() < tolerance);
+        Convergence {
+            statistics,
+            converged,
+            widest,
+        }
+    }
+}
+
+// Runs batches of trials until every pawn-relative face value has a 95%
+// confidence half-width below the tolerance, or the trial budget is spent.
+// Batches are whole multiples of the chunk size, so stopping after `k` trials
+// gives exactly the statistics of a fixed run of `k` trials with the same seed.
+pub fn simulate_until_converged(criteria: &ConvergenceCriteria, seed: u64) -> Convergence {
+    simulate_until_converged_with(criteria, seed, assign_value_to_face, Simulation::pawn)
+}
+
+pub fn simulate_until_converged_with(
+    criteria: &ConvergenceCriteria,
+    seed: u64,
+    value: impl Fn(&Simulation, &Face) -> f64,
+    reference: impl Fn(&Simulation) -> f64,
+) -> Convergence {
+    let assess = |statistics: SimulationStatistics| {
+        Convergence::assess(
+            statistics,
+            criteria.tolerance,
+            &criteria.faces,
+            &value,
+            &reference,
+        )
+    };
+    let statistics = simulate_in_batches(
+        SimulationStatistics::new(),
+        criteria.board,
+        &*criteria.occupancy,
+        criteria.max_trials,
+        seed,
+        criteria.batch_size,
+        |statistics| {
+            if assess(statistics.clone()).converged {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    );
+    assess(statistics)
+}
+
+// An interval that could not be computed, as from a single trial or a
+// reference valued at zero, is wider than any other.
+fn widest_estimate(estimates: &ValueEstimates) -> Option<(Face, Estimate)> {
+    let width = |estimate: &Estimate| match estimate.half_width() {
+        width if width.is_finite() => width,
+        _ => f64::INFINITY,
+    };
+    let ValueEstimates(estimates) = estimates;
+    estimates
+        .iter()
+        .max_by(|(_, a), (_, b)| width(a).total_cmp(&width(b)))
+        .cloned()
+}
@@ -0,0 +1,284 @@
+use std::fmt::Display;
+
+use crate::face::{Direction, Move};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Charset {
+    Ascii,
+    Unicode,
+}
+
+// The squares a face's moves reach, drawn around the piece with north at the
+// top. Range-like moves are drawn a few squares out to show their direction.
+// A square reached by several moves shows the first of them in `Mark` order,
+// so a jump or step always stays visible on top of a range through it.
+pub struct Diagram {
+    radius: i64,
+    marks: Vec<Option<Mark>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Mark {
+    Jump,
+    Step,
+    Knight,
+    Dove,
+    FullLion,
+    LimitedLion,
+    JumpThenRange,
+    FlyingCapture,
+    RangeCapture,
+    FlyingJump,
+    Hook,
+    Range(Line),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Line {
+    Vertical,
+    Horizontal,
+    Rising,
+    Falling,
+}
+
+// How far out range-like moves are drawn.
+const LINE_LENGTH: i64 = 3;
+
+const DIRECTIONS: [Direction; 8] = [
+    Direction::North,
+    Direction::Northeast,
+    Direction::East,
+    Direction::Southeast,
+    Direction::South,
+    Direction::Southwest,
+    Direction::West,
+    Direction::Northwest,
+];
+
+impl Diagram {
+    pub fn new(moves: &[Move]) -> Diagram {
+        let radius = moves.iter().map(reach).max().unwrap_or(0).max(1);
+        let side = (2 * radius + 1) as usize;
+        let mut diagram = Diagram {
+            radius,
+            marks: vec![None; side * side],
+        };
+        for m in moves {
+            diagram.draw(m);
+        }
+        diagram
+    }
+
+    // How many squares the diagram extends from the piece on each side.
+    pub fn radius(&self) -> i64 {
+        self.radius
+    }
+
+    // The glyph drawn `x` squares east and `y` squares south of the piece, if
+    // any move reaches that square.
+    pub fn glyph(&self, x: i64, y: i64, charset: Charset) -> Option<char> {
+        if x.abs() > self.radius || y.abs() > self.radius {
+            return None;
+        }
+        self.marks[self.index(x, y)].map(|mark| mark.glyph(charset))
+    }
+
+    pub fn render(&self, charset: Charset) -> String {
+        let (piece, empty) = match charset {
+            Charset::Ascii => ('@', '.'),
+            Charset::Unicode => ('☗', '·'),
+        };
+        let mut out = String::new();
+        for y in -self.radius..=self.radius {
+            let row: Vec<String> = (-self.radius..=self.radius)
+                .map(|x| match self.glyph(x, y, charset) {
+                    _ if (x, y) == (0, 0) => piece,
+                    Some(glyph) => glyph,
+                    None => empty,
+                })
+                .map(String::from)
+                .collect();
+            out += &row.join(" ");
+            out.push('\n');
+        }
+        out
+    }
+
+    // The glyphs used in this diagram with what they stand for, in the form
+    // "o step  | - range".
+    pub fn legend(&self, charset: Charset) -> String {
+        let mut marks: Vec<Mark> = self.marks.iter().flatten().copied().collect();
+        marks.sort();
+        marks.dedup();
+
+        let mut entries: Vec<(String, &str)> = Vec::new();
+        for mark in marks {
+            match entries.last_mut() {
+                Some((glyphs, description)) if *description == mark.description() => {
+                    glyphs.push(' ');
+                    glyphs.push(mark.glyph(charset));
+                }
+                _ => entries.push((mark.glyph(charset).to_string(), mark.description())),
+            }
+        }
+        let entries: Vec<String> = entries
+            .into_iter()
+            .map(|(glyphs, description)| format!("{glyphs} {description}"))
+            .collect();
+        entries.join("  ")
+    }
+
+    fn index(&self, x: i64, y: i64) -> usize {
+        let side = 2 * self.radius + 1;
+        ((y + self.radius) * side + x + self.radius) as usize
+    }
+
+    fn mark(&mut self, x: i64, y: i64, mark: Mark) {
+        if x.abs() > self.radius || y.abs() > self.radius || (x, y) == (0, 0) {
+            return;
+        }
+        let i = self.index(x, y);
+        self.marks[i] = Some(self.marks[i].map_or(mark, |old| old.min(mark)));
+    }
+
+    fn mark_line(
+        &mut self,
+        direction: &Direction,
+        distances: impl Iterator<Item = i64>,
+        mark: Mark,
+    ) {
+        let (dx, dy) = direction.offset();
+        for k in distances {
+            self.mark(k * dx, k * dy, mark);
+        }
+    }
+
+    fn draw(&mut self, m: &Move) {
+        let radius = self.radius;
+        match m {
+            Move::Step(n, direction) => self.mark_line(direction, 1..=*n as i64, Mark::Step),
+            Move::Range(direction) => {
+                self.mark_line(direction, 1..=radius, Mark::Range(line(direction)))
+            }
+            Move::Jump(n, direction) => {
+                self.mark_line(direction, *n as i64..=*n as i64, Mark::Jump)
+            }
+            Move::JumpOrRange(n, direction) => {
+                self.mark_line(direction, 1..=radius, Mark::Range(line(direction)));
+                self.mark_line(direction, *n as i64..=*n as i64, Mark::Jump);
+            }
+            Move::JumpThenRange(n, direction) => {
+                self.mark_line(direction, *n as i64..=radius, Mark::JumpThenRange)
+            }
+            Move::Hook(direction) => self.mark_line(direction, 1..=radius, Mark::Hook),
+            Move::FlyingJump(direction) => self.mark_line(direction, 1..=radius, Mark::FlyingJump),
+            Move::FlyingCapture(direction) => {
+                self.mark_line(direction, 1..=radius, Mark::FlyingCapture)
+            }
+            Move::RangeCapture(direction) => {
+                self.mark_line(direction, 1..=radius, Mark::RangeCapture)
+            }
+            // The squares the simulation counts: up to three squares past the
+            // third square of each diagonal.
+            Move::Dove => {
+                for direction in DIRECTIONS.iter().filter(|d| !d.is_orthogonal()) {
+                    self.mark_line(direction, 4..=6, Mark::Dove);
+                }
+            }
+            Move::FullLion => {
+                for y in -2..=2 {
+                    for x in -2..=2 {
+                        self.mark(x, y, Mark::FullLion);
+                    }
+                }
+            }
+            Move::LimitedLion => {
+                for direction in &DIRECTIONS {
+                    self.mark_line(direction, 1..=3, Mark::LimitedLion);
+                }
+            }
+            Move::KnightForward => {
+                self.mark(-1, -2, Mark::Knight);
+                self.mark(1, -2, Mark::Knight);
+            }
+            Move::KnightBackward => {
+                self.mark(-1, 2, Mark::Knight);
+                self.mark(1, 2, Mark::Knight);
+            }
+        }
+    }
+}
+
+impl Display for Diagram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(Charset::Unicode))
+    }
+}
+
+impl Mark {
+    fn glyph(self, charset: Charset) -> char {
+        let (ascii, unicode) = match self {
+            Mark::Jump => ('x', '✕'),
+            Mark::Step => ('o', '○'),
+            Mark::Knight => ('n', '♘'),
+            Mark::Dove => ('d', '◈'),
+            Mark::FullLion => ('L', '◆'),
+            Mark::LimitedLion => ('l', '◇'),
+            Mark::JumpThenRange => ('j', '▷'),
+            Mark::FlyingCapture => ('c', '▲'),
+            Mark::RangeCapture => ('g', '●'),
+            Mark::FlyingJump => ('f', '△'),
+            Mark::Hook => ('h', '┼'),
+            Mark::Range(Line::Vertical) => ('|', '│'),
+            Mark::Range(Line::Horizontal) => ('-', '─'),
+            Mark::Range(Line::Rising) => ('/', '╱'),
+            Mark::Range(Line::Falling) => ('\\', '╲'),
+        };
+        match charset {
+            Charset::Ascii => ascii,
+            Charset::Unicode => unicode,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Mark::Jump => "jump",
+            Mark::Step => "step",
+            Mark::Knight => "knight",
+            Mark::Dove => "dove",
+            Mark::FullLion => "lion",
+            Mark::LimitedLion => "limited lion",
+            Mark::JumpThenRange => "jump then range",
+            Mark::FlyingCapture => "flying capture",
+            Mark::RangeCapture => "range capture",
+            Mark::FlyingJump => "flying jump",
+            Mark::Hook => "hook",
+            Mark::Range(_) => "range",
+        }
+    }
+}
+
+fn line(direction: &Direction) -> Line {
+    match direction {
+        Direction::North | Direction::South => Line::Vertical,
+        Direction::East | Direction::West => Line::Horizontal,
+        Direction::Northeast | Direction::Southwest => Line::Rising,
+        Direction::Southeast | Direction::Northwest => Line::Falling,
+    }
+}
+
+// How many squares from the piece a move needs to be drawn.
+fn reach(m: &Move) -> i64 {
+    match m {
+        Move::Step(n, _) | Move::Jump(n, _) => *n as i64,
+        Move::JumpOrRange(n, _) | Move::JumpThenRange(n, _) => (*n as i64 + 1).max(LINE_LENGTH),
+        Move::Range(_)
+        | Move::Hook(_)
+        | Move::FlyingJump(_)
+        | Move::FlyingCapture(_)
+        | Move::RangeCapture(_) => LINE_LENGTH,
+        Move::Dove => 6,
+        Move::FullLion | Move::KnightForward | Move::KnightBackward => 2,
+        Move::LimitedLion => 3,
+    }
+}
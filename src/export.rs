@@ -0,0 +1,318 @@
+use serde::Serialize;
+use std::fmt::Display;
+
+use crate::face::Face;
+use crate::simulate::{Estimate, Simulation};
+use crate::{BoardComparison, OpaqueNumber, ValueAssignments, ValueEstimates};
+
+#[derive(Debug)]
+pub enum FormatError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Toml(toml::de::Error),
+    Csv { line: usize, message: String },
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::Io(e) => write!(f, "{e}"),
+            FormatError::Json(e) => write!(f, "invalid JSON: {e}"),
+            FormatError::Toml(e) => write!(f, "invalid TOML: {e}"),
+            FormatError::Csv { line, message } => {
+                write!(f, "invalid CSV on line {line}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl From<std::io::Error> for FormatError {
+    fn from(e: std::io::Error) -> Self {
+        FormatError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for FormatError {
+    fn from(e: serde_json::Error) -> Self {
+        FormatError::Json(e)
+    }
+}
+
+impl From<toml::de::Error> for FormatError {
+    fn from(e: toml::de::Error) -> Self {
+        FormatError::Toml(e)
+    }
+}
+
+#[derive(Serialize)]
+struct FaceFields<'a> {
+    name: &'a str,
+    kanji: &'a str,
+    additional_kanji: Option<&'a str>,
+    romaji: &'a str,
+    promotion: Option<&'a str>,
+}
+
+impl<'a> FaceFields<'a> {
+    fn new(face: &'a Face) -> Self {
+        FaceFields {
+            name: &face.name,
+            kanji: &face.kanji,
+            additional_kanji: face.additional_kanji.as_deref(),
+            romaji: &face.romaji,
+            promotion: face.promotion.as_deref(),
+        }
+    }
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.name.to_string(),
+            self.kanji.to_string(),
+            self.additional_kanji.unwrap_or_default().to_string(),
+            self.romaji.to_string(),
+            self.promotion.unwrap_or_default().to_string(),
+        ]
+    }
+}
+
+const FACE_HEADER: [&str; 5] = ["name", "kanji", "additional_kanji", "romaji", "promotion"];
+
+#[derive(Serialize)]
+struct FaceValue<'a> {
+    #[serde(flatten)]
+    face: FaceFields<'a>,
+    value: f64,
+}
+
+impl<'a> FaceValue<'a> {
+    fn new(face: &'a Face, value: f64) -> Self {
+        FaceValue {
+            face: FaceFields::new(face),
+            value,
+        }
+    }
+
+    fn csv_fields(&self) -> Vec<String> {
+        let mut fields = self.face.csv_fields();
+        fields.push(self.value.to_string());
+        fields
+    }
+}
+
+const FACE_VALUE_HEADER: [&str; 6] = [
+    FACE_HEADER[0],
+    FACE_HEADER[1],
+    FACE_HEADER[2],
+    FACE_HEADER[3],
+    FACE_HEADER[4],
+    "value",
+];
+
+#[derive(Serialize)]
+struct FaceEstimate<'a> {
+    #[serde(flatten)]
+    face: FaceValue<'a>,
+    standard_error: f64,
+    lower: f64,
+    upper: f64,
+}
+
+impl<'a> FaceEstimate<'a> {
+    fn new(face: &'a Face, estimate: &Estimate) -> Self {
+        let (lower, upper) = estimate.confidence_interval();
+        FaceEstimate {
+            face: FaceValue::new(face, estimate.mean),
+            standard_error: estimate.standard_error,
+            lower,
+            upper,
+        }
+    }
+
+    fn csv_fields(&self) -> Vec<String> {
+        let mut fields = self.face.csv_fields();
+        fields.extend([self.standard_error, self.lower, self.upper].map(|x| x.to_string()));
+        fields
+    }
+}
+
+impl OpaqueNumber {
+    pub fn value(&self) -> f64 {
+        let OpaqueNumber(n) = self;
+        *n as f64 / 2.0
+    }
+}
+
+impl ValueAssignments {
+    fn rows(&self) -> Vec<FaceValue<'_>> {
+        let ValueAssignments(assignments) = self;
+        assignments
+            .iter()
+            .map(|(face, points)| FaceValue::new(face, points.value()))
+            .collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.rows()).unwrap()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let rows = self.rows().iter().map(FaceValue::csv_fields).collect();
+        write_csv(&FACE_VALUE_HEADER, rows)
+    }
+}
+
+impl ValueEstimates {
+    fn rows(&self) -> Vec<FaceEstimate<'_>> {
+        let ValueEstimates(estimates) = self;
+        estimates
+            .iter()
+            .map(|(face, estimate)| FaceEstimate::new(face, estimate))
+            .collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.rows()).unwrap()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut header = FACE_VALUE_HEADER.to_vec();
+        header.extend(["standard_error", "lower", "upper"]);
+        let rows = self.rows().iter().map(FaceEstimate::csv_fields).collect();
+        write_csv(&header, rows)
+    }
+}
+
+#[derive(Serialize)]
+struct FaceBoardValues<'a> {
+    #[serde(flatten)]
+    face: FaceFields<'a>,
+    values: Vec<BoardValue<'a>>,
+}
+
+#[derive(Serialize)]
+struct BoardValue<'a> {
+    board: &'a str,
+    width: usize,
+    height: usize,
+    value: f64,
+}
+
+impl BoardComparison {
+    fn rows(&self) -> Vec<FaceBoardValues<'_>> {
+        self.values
+            .iter()
+            .map(|(face, values)| FaceBoardValues {
+                face: FaceFields::new(face),
+                values: self
+                    .boards
+                    .iter()
+                    .zip(values)
+                    .map(|((name, board), &value)| BoardValue {
+                        board: name,
+                        width: board.width(),
+                        height: board.height(),
+                        value,
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.rows()).unwrap()
+    }
+
+    // One column per board after the face's own fields.
+    pub fn to_csv(&self) -> String {
+        let mut header = FACE_HEADER.to_vec();
+        header.extend(self.boards.iter().map(|(name, _)| name.as_str()));
+        let rows = self
+            .rows()
+            .iter()
+            .map(|row| {
+                let mut fields = row.face.csv_fields();
+                fields.extend(row.values.iter().map(|v| v.value.to_string()));
+                fields
+            })
+            .collect();
+        write_csv(&header, rows)
+    }
+}
+
+impl Simulation {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn from_json(json: &str) -> Result<Simulation, FormatError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let rows = Simulation::field_names()
+            .into_iter()
+            .zip(self.to_fields())
+            .map(|(name, value)| vec![name, value.to_string()])
+            .collect();
+        write_csv(&["field", "value"], rows)
+    }
+
+    pub fn from_csv(csv: &str) -> Result<Simulation, FormatError> {
+        let names = Simulation::field_names();
+        let mut fields = vec![None; Simulation::NUM_FIELDS];
+
+        for (i, row) in csv.lines().enumerate().skip(1) {
+            let line = i + 1;
+            let error = |message: String| FormatError::Csv { line, message };
+            if row.trim().is_empty() {
+                continue;
+            }
+
+            let (name, value) = row
+                .split_once(',')
+                .ok_or_else(|| error("expected `field,value`".to_string()))?;
+            let index = names
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| error(format!("unknown field `{name}`")))?;
+            let value = value
+                .trim()
+                .parse()
+                .map_err(|e| error(format!("invalid value for `{name}`: {e}")))?;
+            fields[index] = Some(value);
+        }
+
+        let fields = fields
+            .into_iter()
+            .zip(&names)
+            .map(|(value, name)| {
+                value.ok_or_else(|| FormatError::Csv {
+                    line: csv.lines().count(),
+                    message: format!("missing field `{name}`"),
+                })
+            })
+            .collect::<Result<Vec<f64>, _>>()?;
+        Ok(Simulation::from_fields(&fields))
+    }
+}
+
+fn write_csv(header: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut csv = header.join(",");
+    csv.push('\n');
+    for row in rows {
+        let row: Vec<String> = row.iter().map(|field| escape_csv(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -1,4 +1,11 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::export::FormatError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Direction {
     North,
     Northeast,
@@ -11,6 +18,22 @@ pub enum Direction {
 }
 
 impl Direction {
+    // Clockwise from north, in the order `index` numbers them.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::Northeast,
+        Direction::East,
+        Direction::Southeast,
+        Direction::South,
+        Direction::Southwest,
+        Direction::West,
+        Direction::Northwest,
+    ];
+
+    pub fn index(&self) -> usize {
+        self.clone() as usize
+    }
+
     pub fn is_orthogonal(&self) -> bool {
         match self {
             Direction::North => true,
@@ -23,9 +46,53 @@ impl Direction {
             Direction::Northwest => false,
         }
     }
+
+    // One square in this direction as (dx, dy), with north towards smaller y
+    // as on the simulated board.
+    pub fn offset(&self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::Northeast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::Southeast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::Southwest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::Northwest => (-1, -1),
+        }
+    }
+}
+
+// Taikyoku's hierarchy for range captures, lowest first. A range-capturing
+// piece may only pass pieces ranked below itself; the jumping generals are the
+// Rook and Bishop Generals, the Ferocious Dragon and the Flying Crocodile.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Rank {
+    #[default]
+    Ordinary,
+    JumpingGeneral,
+    ViceGeneral,
+    GreatGeneral,
+    Royal,
+}
+
+impl Rank {
+    pub const ALL: [Rank; 5] = [
+        Rank::Ordinary,
+        Rank::JumpingGeneral,
+        Rank::ViceGeneral,
+        Rank::GreatGeneral,
+        Rank::Royal,
+    ];
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Move {
     Step(u32, Direction),
     Range(Direction),
@@ -40,4436 +107,84 @@ pub enum Move {
     FlyingJump(Direction),
     FlyingCapture(Direction),
     JumpOrRange(u32, Direction),
+    // Flies along the line over any pieces ranked below the moving face,
+    // stopping on an empty square or capturing an opponent.
+    RangeCapture(Direction),
 }
 
-#[derive(Clone)]
+impl Move {
+    pub fn direction(&self) -> Option<&Direction> {
+        match self {
+            Move::Step(_, direction)
+            | Move::Range(direction)
+            | Move::Jump(_, direction)
+            | Move::Hook(direction)
+            | Move::JumpThenRange(_, direction)
+            | Move::FlyingJump(direction)
+            | Move::FlyingCapture(direction)
+            | Move::JumpOrRange(_, direction)
+            | Move::RangeCapture(direction) => Some(direction),
+            Move::Dove
+            | Move::KnightForward
+            | Move::KnightBackward
+            | Move::FullLion
+            | Move::LimitedLion => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Face {
-    pub name: &'static str,
-    pub kanji: &'static str,
-    pub additional_kanji: Option<&'static str>,
-    pub romaji: &'static str,
-    pub promotion: Option<&'static str>,
+    pub name: String,
+    pub kanji: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_kanji: Option<String>,
+    pub romaji: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promotion: Option<String>,
+    // Only range-capturing moves care about rank: they cannot pass a piece of
+    // the same or a higher rank.
+    #[serde(default, skip_serializing_if = "is_ordinary")]
+    pub rank: Rank,
     pub moves: Vec<Move>,
 }
 
+fn is_ordinary(rank: &Rank) -> bool {
+    *rank == Rank::Ordinary
+}
+
+#[derive(Deserialize)]
+struct FaceTable {
+    faces: Vec<Face>,
+}
+
+const TAIKYOKU: &str = include_str!("../data/taikyoku.toml");
+
+// The built-in taikyoku faces. The embedded table is parsed on first use.
 pub fn faces() -> Vec<Face> {
-    vec![
-        Face {
-            name: "King",
-            kanji: "玉将",
-            additional_kanji: None,
-            romaji: "gyokushō",
-            promotion: None,
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Crown Prince",
-            kanji: "太子",
-            additional_kanji: None,
-            romaji: "taishi",
-            promotion: Some("King"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Gold General",
-            kanji: "金将",
-            additional_kanji: None,
-            romaji: "kinshō",
-            promotion: Some("Rook"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Right General",
-            kanji: "右将",
-            additional_kanji: None,
-            romaji: "ushō",
-            promotion: Some("Right Army"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Left General",
-            kanji: "左将",
-            additional_kanji: None,
-            romaji: "sashō",
-            promotion: Some("Left Army"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Rear Standard",
-            kanji: "後旗",
-            additional_kanji: None,
-            romaji: "kōki",
-            promotion: Some("Middle Standard"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free King",
-            kanji: "奔王",
-            additional_kanji: None,
-            romaji: "honnō",
-            promotion: Some("Great General"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Tapir",
-            kanji: "奔獏",
-            additional_kanji: None,
-            romaji: "honbaku",
-            promotion: Some("Free King"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(5, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(5, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Dove",
-            kanji: "鳩槃",
-            additional_kanji: None,
-            romaji: "kyūhan",
-            promotion: None,
-            moves: vec![
-                Move::Dove,
-                Move::Step(2, Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Ground Dragon",
-            kanji: "地龍",
-            additional_kanji: None,
-            romaji: "jiryū",
-            promotion: Some("Rain Dragon"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Demon",
-            kanji: "奔鬼",
-            additional_kanji: None,
-            romaji: "honki",
-            promotion: Some("Free King"),
-            moves: vec![
-                Move::Step(5, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(5, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Horse",
-            kanji: "走馬",
-            additional_kanji: None,
-            romaji: "sōba",
-            promotion: Some("Free Demon"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Jump(2, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Jump(2, Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Beastly Jailor",
-            kanji: "獣曹",
-            additional_kanji: None,
-            romaji: "jūsō",
-            promotion: Some("Beastly Herald"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Long-nosed Goblin",
-            kanji: "天狗",
-            additional_kanji: None,
-            romaji: "tengu",
-            promotion: None,
-            moves: vec![
-                Move::Hook(Direction::Northeast),
-                Move::Hook(Direction::Southeast),
-                Move::Hook(Direction::Southwest),
-                Move::Hook(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Right Mountain Eagle",
-            kanji: "山鷲",
-            additional_kanji: Some("右"),
-            romaji: "usanshū",
-            promotion: Some("Soaring Eagle"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::JumpOrRange(2, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::JumpOrRange(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Left Mountain Eagle",
-            kanji: "山鷲",
-            additional_kanji: Some("左"),
-            romaji: "sasanshū",
-            promotion: Some("Soaring Eagle"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::JumpOrRange(2, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::JumpOrRange(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Fire Demon",
-            kanji: "火鬼",
-            additional_kanji: None,
-            romaji: "kaki",
-            promotion: Some("Free Fire"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Whale",
-            kanji: "鯨鯢",
-            additional_kanji: None,
-            romaji: "keigei",
-            promotion: Some("Great Whale"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Running Hare",
-            kanji: "走兎",
-            additional_kanji: None,
-            romaji: "sōto",
-            promotion: Some("Treacherous Fox"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "White Tiger",
-            kanji: "白虎",
-            additional_kanji: None,
-            romaji: "byakko",
-            promotion: Some("Divine Tiger"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Turtle Snake",
-            kanji: "玄武",
-            additional_kanji: None,
-            romaji: "genbu",
-            promotion: Some("Divine Turtle"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Pidgeon",
-            kanji: "鳩槃",
-            additional_kanji: None,
-            romaji: "kyūhan",
-            promotion: None,
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Lance",
-            kanji: "香車",
-            additional_kanji: None,
-            romaji: "kyōsha",
-            promotion: Some("White Horse"),
-            moves: vec![Move::Range(Direction::North)],
-        },
-        Face {
-            name: "Reverse Chariot",
-            kanji: "反車",
-            additional_kanji: None,
-            romaji: "hensha",
-            promotion: Some("Whale"),
-            moves: vec![Move::Range(Direction::North), Move::Range(Direction::South)],
-        },
-        Face {
-            name: "Fragrant Elephant",
-            kanji: "香象",
-            additional_kanji: None,
-            romaji: "kōzō",
-            promotion: Some("Elephant King"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "White Elephant",
-            kanji: "白象",
-            additional_kanji: None,
-            romaji: "hakuzō",
-            promotion: Some("Elephant King"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Mountain Dove",
-            kanji: "山鳩",
-            additional_kanji: None,
-            romaji: "sankyū",
-            promotion: Some("Great Dove"),
-            moves: vec![
-                Move::Step(5, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(5, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Flying Swallow",
-            kanji: "飛燕",
-            additional_kanji: None,
-            romaji: "hien",
-            promotion: Some("Rook"),
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Bird Herald",
-            kanji: "禽吏",
-            additional_kanji: None,
-            romaji: "kinri",
-            promotion: Some("The Birds"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::Step(3, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Rain Dragon",
-            kanji: "雨龍",
-            additional_kanji: None,
-            romaji: "uryū",
-            promotion: Some("Great Dragon"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Forest demon",
-            kanji: "森鬼",
-            additional_kanji: None,
-            romaji: "shinki",
-            promotion: Some("Thunderous Running"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Mountain Deer",
-            kanji: "山鹿",
-            additional_kanji: None,
-            romaji: "sanroku",
-            promotion: Some("Great Deer"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(4, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Puppy",
-            kanji: "走狗",
-            additional_kanji: None,
-            romaji: "sōku",
-            promotion: Some("Free Puppy"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Running Snake",
-            kanji: "走蛇",
-            additional_kanji: None,
-            romaji: "sōja",
-            promotion: Some("Free Snake"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Side Snake",
-            kanji: "横蛇",
-            additional_kanji: None,
-            romaji: "ōja",
-            promotion: Some("Great Unicorn"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Great Dove",
-            kanji: "大鳩",
-            additional_kanji: None,
-            romaji: "daikyū",
-            promotion: Some("Dove"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(3, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Tiger",
-            kanji: "走虎",
-            additional_kanji: None,
-            romaji: "sōko",
-            promotion: Some("Free Tiger"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Running Bear",
-            kanji: "走熊",
-            additional_kanji: None,
-            romaji: "sōyū",
-            promotion: Some("Free Bear"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Night Sword",
-            kanji: "夜叉",
-            additional_kanji: None,
-            romaji: "yasha",
-            promotion: Some("Four Heavens"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Buddhist Devil",
-            kanji: "羅刹",
-            additional_kanji: None,
-            romaji: "rasetsu",
-            promotion: Some("Four Heavens"),
-            moves: vec![
-                Move::Step(3, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Guardian of the Gods",
-            kanji: "金剛",
-            additional_kanji: None,
-            romaji: "kongō",
-            promotion: Some("Four Heavens"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(3, Direction::East),
-                Move::Step(3, Direction::South),
-                Move::Step(3, Direction::West),
-            ],
-        },
-        Face {
-            name: "Wrestler",
-            kanji: "力士",
-            additional_kanji: None,
-            romaji: "rikishi",
-            promotion: Some("Four Heavens"),
-            moves: vec![
-                Move::Step(3, Direction::Northeast),
-                Move::Step(3, Direction::Southeast),
-                Move::Step(3, Direction::Southwest),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Silver General",
-            kanji: "銀将",
-            additional_kanji: None,
-            romaji: "ginshō",
-            promotion: Some("Vertical Mover"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Drunken Elephant",
-            kanji: "酔象",
-            additional_kanji: None,
-            romaji: "suizō",
-            promotion: Some("Crown Prince"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Neighboring King",
-            kanji: "近王",
-            additional_kanji: None,
-            romaji: "kinnō",
-            promotion: Some("Standard Bearer"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Gold Chariot",
-            kanji: "金車",
-            additional_kanji: None,
-            romaji: "kinsha",
-            promotion: Some("Playful Dove"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Side Dragon",
-            kanji: "横龍",
-            additional_kanji: None,
-            romaji: "ōryū",
-            promotion: Some("Running Dragon"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::East),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Running Deer",
-            kanji: "走鹿",
-            additional_kanji: None,
-            romaji: "sōroku",
-            promotion: Some("Free Deer"),
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Wolf",
-            kanji: "走狼",
-            additional_kanji: None,
-            romaji: "sōrō",
-            promotion: Some("Free Wolf"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Bishop General",
-            kanji: "角将",
-            additional_kanji: None,
-            romaji: "kakushō",
-            promotion: Some("Monsoon Demon"),
-            moves: vec![
-                Move::FlyingCapture(Direction::Northeast),
-                Move::FlyingCapture(Direction::Southeast),
-                Move::FlyingCapture(Direction::Southwest),
-                Move::FlyingCapture(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Rook General",
-            kanji: "飛将",
-            additional_kanji: None,
-            romaji: "hishō",
-            promotion: Some("Flying Crocodile"),
-            moves: vec![
-                Move::FlyingCapture(Direction::North),
-                Move::FlyingCapture(Direction::East),
-                Move::FlyingCapture(Direction::South),
-                Move::FlyingCapture(Direction::West),
-            ],
-        },
-        Face {
-            name: "Right Tiger",
-            kanji: "右虎",
-            additional_kanji: None,
-            romaji: "uko",
-            promotion: Some("White Tiger"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Left Tiger",
-            kanji: "左虎",
-            additional_kanji: None,
-            romaji: "sako",
-            promotion: Some("Turtle Snake"),
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Right Dragon",
-            kanji: "右龍",
-            additional_kanji: None,
-            romaji: "uryū",
-            promotion: Some("Blue Dragon"),
-            moves: vec![
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Left Dragon",
-            kanji: "左龍",
-            additional_kanji: None,
-            romaji: "saryū",
-            promotion: Some("Vermillion Sparrow"),
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Beastly Herald",
-            kanji: "獣吏",
-            additional_kanji: None,
-            romaji: "jūri",
-            promotion: Some("Beastly Bird"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::Step(3, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Wind Dragon",
-            kanji: "風龍",
-            additional_kanji: None,
-            romaji: "fūryū",
-            promotion: Some("Free Dragon"),
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Puppy",
-            kanji: "奔狗",
-            additional_kanji: None,
-            romaji: "honku",
-            promotion: Some("Free Dog"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Bird",
-            kanji: "行鳥",
-            additional_kanji: None,
-            romaji: "gyōchō",
-            promotion: Some("Free Demon"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Old Kite Hawk",
-            kanji: "古鵄",
-            additional_kanji: None,
-            romaji: "kotetsu",
-            promotion: Some("Long-nosed Goblin"),
-            moves: vec![
-                Move::Step(2, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Peacock",
-            kanji: "孔雀",
-            additional_kanji: None,
-            romaji: "kujaku",
-            promotion: Some("Long-nosed Goblin"),
-            moves: vec![
-                Move::Hook(Direction::Northeast),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::Southwest),
-                Move::Hook(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Water Dragon",
-            kanji: "水龍",
-            additional_kanji: None,
-            romaji: "suiryū",
-            promotion: Some("Phoenix Teacher"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(4, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(4, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Fire Dragon",
-            kanji: "火龍",
-            additional_kanji: None,
-            romaji: "karyū",
-            promotion: Some("Kylin Teacher"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(4, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(4, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Copper General",
-            kanji: "銅将",
-            additional_kanji: None,
-            romaji: "dōshō",
-            promotion: Some("Side Mover"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Phoenix Teacher",
-            kanji: "鳳師",
-            additional_kanji: None,
-            romaji: "hōshi",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::JumpOrRange(3, Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::JumpOrRange(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Kylin Teacher",
-            kanji: "麟師",
-            additional_kanji: None,
-            romaji: "rinshi",
-            promotion: None,
-            moves: vec![
-                Move::JumpOrRange(3, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::JumpOrRange(3, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Silver Chariot",
-            kanji: "銀車",
-            additional_kanji: None,
-            romaji: "ginsha",
-            promotion: Some("Swan's Wings"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Vertical Bear",
-            kanji: "竪熊",
-            additional_kanji: None,
-            romaji: "shuyū",
-            promotion: Some("Free Bear"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Knight",
-            kanji: "桂馬",
-            additional_kanji: None,
-            romaji: "keima",
-            promotion: Some("Side Soldier"),
-            moves: vec![Move::KnightForward],
-        },
-        Face {
-            name: "Pig General",
-            kanji: "豚将",
-            additional_kanji: None,
-            romaji: "tonshō",
-            promotion: Some("Free Pig"),
-            moves: vec![
-                Move::Step(4, Direction::Northeast),
-                Move::Step(2, Direction::South),
-                Move::Step(4, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Cock General",
-            kanji: "鶏将",
-            additional_kanji: None,
-            romaji: "keishō",
-            promotion: Some("Free Rooster"),
-            moves: vec![
-                Move::Step(4, Direction::North),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Puppy General",
-            kanji: "狗将",
-            additional_kanji: None,
-            romaji: "kushō",
-            promotion: Some("Free Dog"),
-            moves: vec![
-                Move::Step(4, Direction::North),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Horse General",
-            kanji: "馬将",
-            additional_kanji: None,
-            romaji: "bashō",
-            promotion: Some("Free Horse"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Ox General",
-            kanji: "牛将",
-            additional_kanji: None,
-            romaji: "gyūshō",
-            promotion: Some("Free Ox"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Middle Standard",
-            kanji: "中旗",
-            additional_kanji: None,
-            romaji: "chūki",
-            promotion: Some("Standard Bearer"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(3, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Side Boar",
-            kanji: "横猪",
-            additional_kanji: None,
-            romaji: "ōcho",
-            promotion: Some("Free Boar"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Silver Hare",
-            kanji: "銀兎",
-            additional_kanji: None,
-            romaji: "ginto",
-            promotion: Some("Whale"),
-            moves: vec![
-                Move::Step(2, Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::Southwest),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Golden Deer",
-            kanji: "金鹿",
-            additional_kanji: None,
-            romaji: "konroku",
-            promotion: Some("White Horse"),
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Lion",
-            kanji: "獅子",
-            additional_kanji: None,
-            romaji: "shishi",
-            promotion: Some("Furious Fiend"),
-            moves: vec![Move::FullLion],
-        },
-        Face {
-            name: "Bird Jailer",
-            kanji: "禽曹",
-            additional_kanji: None,
-            romaji: "kinsō",
-            promotion: Some("Bird Herald"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::Step(3, Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Deer",
-            kanji: "大鹿",
-            additional_kanji: None,
-            romaji: "dairoku",
-            promotion: Some("Free Deer"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Jump(2, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Jump(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Ferocious Dragon",
-            kanji: "猛龍",
-            additional_kanji: None,
-            romaji: "mōryū",
-            promotion: Some("Great Dragon"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::FlyingCapture(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::FlyingCapture(Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::FlyingCapture(Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::FlyingCapture(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Woodland Demon",
-            kanji: "林鬼",
-            additional_kanji: None,
-            romaji: "rinki",
-            promotion: Some("Right Phoenix"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Deputy General",
-            kanji: "副将",
-            additional_kanji: None,
-            romaji: "fukushō",
-            promotion: Some("Great General"),
-            moves: vec![
-                Move::Jump(2, Direction::North),
-                Move::FlyingCapture(Direction::Northeast),
-                Move::Jump(2, Direction::East),
-                Move::FlyingCapture(Direction::Southeast),
-                Move::Jump(2, Direction::South),
-                Move::FlyingCapture(Direction::Southwest),
-                Move::Jump(2, Direction::West),
-                Move::FlyingCapture(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great General",
-            kanji: "大将",
-            additional_kanji: None,
-            romaji: "taishō",
-            promotion: None,
-            moves: vec![
-                Move::FlyingCapture(Direction::North),
-                Move::FlyingCapture(Direction::Northeast),
-                Move::FlyingCapture(Direction::East),
-                Move::FlyingCapture(Direction::Southeast),
-                Move::FlyingCapture(Direction::South),
-                Move::FlyingCapture(Direction::Southwest),
-                Move::FlyingCapture(Direction::West),
-                Move::FlyingCapture(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Stone Chariot",
-            kanji: "石車",
-            additional_kanji: None,
-            romaji: "sekisha",
-            promotion: Some("Walking Heron"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Cloud Eagle",
-            kanji: "雲鷲",
-            additional_kanji: None,
-            romaji: "unjū",
-            promotion: Some("Sinewy Eagle"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Bishop",
-            kanji: "角行",
-            additional_kanji: None,
-            romaji: "kakugyō",
-            promotion: Some("Dragon Horse"),
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Rook",
-            kanji: "飛車",
-            additional_kanji: None,
-            romaji: "hisha",
-            promotion: Some("Dragon King"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Side Wolf",
-            kanji: "横狼",
-            additional_kanji: None,
-            romaji: "ōrō",
-            promotion: Some("Free Wolf"),
-            moves: vec![
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Flying Cat",
-            kanji: "飛猫",
-            additional_kanji: None,
-            romaji: "hibyō",
-            promotion: Some("Rook"),
-            moves: vec![
-                Move::Jump(3, Direction::North),
-                Move::Jump(3, Direction::Northeast),
-                Move::Jump(3, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Jump(3, Direction::West),
-                Move::Jump(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Mountain Eagle",
-            kanji: "山鷹",
-            additional_kanji: None,
-            romaji: "san'ō",
-            promotion: Some("Horned Falcon"),
-            moves: vec![
-                Move::JumpOrRange(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Vertical Tiger",
-            kanji: "竪虎",
-            additional_kanji: None,
-            romaji: "shuko",
-            promotion: Some("Free Tiger"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::South),
-            ],
-        },
-        Face {
-            name: "Soldier",
-            kanji: "兵士",
-            additional_kanji: None,
-            romaji: "heishi",
-            promotion: Some("Horseman"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Small Standard",
-            kanji: "小旗",
-            additional_kanji: None,
-            romaji: "shōki",
-            promotion: Some("Rear Standard"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Cloud Dragon",
-            kanji: "雲龍",
-            additional_kanji: None,
-            romaji: "unryū",
-            promotion: Some("Great Dragon"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Copper Chariot",
-            kanji: "銅車",
-            additional_kanji: None,
-            romaji: "dōsha",
-            promotion: Some("Copper Elephant"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Range(Direction::South),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Chariot",
-            kanji: "走車",
-            additional_kanji: None,
-            romaji: "sōsha",
-            promotion: Some("Cannon Chariot"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Ramshead Soldier",
-            kanji: "羊兵",
-            additional_kanji: None,
-            romaji: "yōhei",
-            promotion: Some("Tiger Soldier"),
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Violent Ox",
-            kanji: "猛牛",
-            additional_kanji: None,
-            romaji: "mōgyū",
-            promotion: Some("Flying Ox"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Dragon",
-            kanji: "大龍",
-            additional_kanji: None,
-            romaji: "dairyū",
-            promotion: Some("Primeval Dragon"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Step(3, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Golden Bird",
-            kanji: "金翅",
-            additional_kanji: None,
-            romaji: "kinshi",
-            promotion: Some("Free Insect"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::FlyingJump(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(3, Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::FlyingJump(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Dark Spirit",
-            kanji: "無明",
-            additional_kanji: None,
-            romaji: "mumyō",
-            promotion: Some("Buddhist Spirit"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Deva",
-            kanji: "提婆",
-            additional_kanji: None,
-            romaji: "daiba",
-            promotion: Some("Teaching King"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Wood Chariot",
-            kanji: "木車",
-            additional_kanji: None,
-            romaji: "mokusha",
-            promotion: Some("Wind Turtle"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "White Horse",
-            kanji: "白駒",
-            additional_kanji: None,
-            romaji: "hakku",
-            promotion: Some("Great Colt"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Right Howling Dog",
-            kanji: "𠵇犬",
-            additional_kanji: Some("右"),
-            romaji: "ukiken",
-            promotion: Some("Right Dog"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::South),
-            ],
-        },
-        Face {
-            name: "Left Howling Dog",
-            kanji: "𠵇犬",
-            additional_kanji: Some("左"),
-            romaji: "sakiken",
-            promotion: Some("Left Dog"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::South),
-            ],
-        },
-        Face {
-            name: "Side Mover",
-            kanji: "横行",
-            additional_kanji: None,
-            romaji: "ōgyō",
-            promotion: Some("Free Boar"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Prancing Stag",
-            kanji: "踊鹿",
-            additional_kanji: None,
-            romaji: "yōroku",
-            promotion: Some("Square Mover"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Water Buffalo",
-            kanji: "水牛",
-            additional_kanji: None,
-            romaji: "suigyū",
-            promotion: Some("Great Tapir"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Ferocious Leapord",
-            kanji: "猛豹",
-            additional_kanji: None,
-            romaji: "mōhyō",
-            promotion: Some("Bishop"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Fierce Eagle",
-            kanji: "猛鷲",
-            additional_kanji: None,
-            romaji: "mōjū",
-            promotion: Some("Soaring Eagle"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Flying Dragon",
-            kanji: "飛龍",
-            additional_kanji: None,
-            romaji: "hiryū",
-            promotion: Some("Dragon King"),
-            moves: vec![
-                Move::Jump(2, Direction::Northeast),
-                Move::Jump(2, Direction::Southeast),
-                Move::Jump(2, Direction::Southwest),
-                Move::Jump(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Poisonous Snake",
-            kanji: "毒蛇",
-            additional_kanji: None,
-            romaji: "dokuja",
-            promotion: Some("Hook Mover"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Flying Goose",
-            kanji: "鳫飛",
-            additional_kanji: None,
-            romaji: "ganhi",
-            promotion: Some("Swallow's Wings"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Bird Runner",
-            kanji: "烏行",
-            additional_kanji: None,
-            romaji: "ukō",
-            promotion: Some("Flying Hawk"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Blind Dog",
-            kanji: "盲犬",
-            additional_kanji: None,
-            romaji: "mōken",
-            promotion: Some("Ferocious Deer"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Water General",
-            kanji: "水将",
-            additional_kanji: None,
-            romaji: "suishō",
-            promotion: Some("Deputy General"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Fire General",
-            kanji: "火将",
-            additional_kanji: None,
-            romaji: "kashō",
-            promotion: Some("Great General"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(3, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Phoenix",
-            kanji: "鳳凰",
-            additional_kanji: None,
-            romaji: "hōō",
-            promotion: Some("Golden Bird"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Jump(2, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Jump(2, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Jump(2, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Jump(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Kylin",
-            kanji: "麒麟",
-            additional_kanji: None,
-            romaji: "kirin",
-            promotion: Some("Golden Bird"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Jump(2, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Jump(2, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Hook Mover",
-            kanji: "鉤行",
-            additional_kanji: None,
-            romaji: "kōgyō",
-            promotion: None,
-            moves: vec![
-                Move::Hook(Direction::North),
-                Move::Hook(Direction::East),
-                Move::Hook(Direction::South),
-                Move::Hook(Direction::West),
-            ],
-        },
-        Face {
-            name: "Small Turtle",
-            kanji: "小亀",
-            additional_kanji: None,
-            romaji: "shōki",
-            promotion: Some("Treasure Turtle"),
-            moves: vec![
-                Move::JumpOrRange(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::JumpOrRange(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Large Turtle",
-            kanji: "大亀",
-            additional_kanji: None,
-            romaji: "daiki",
-            promotion: Some("Spirit Turtle"),
-            moves: vec![
-                Move::JumpOrRange(3, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::JumpOrRange(3, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Capricorn",
-            kanji: "摩羯",
-            additional_kanji: None,
-            romaji: "makatsu",
-            promotion: Some("Hook Mover"),
-            moves: vec![
-                Move::Hook(Direction::Northeast),
-                Move::Hook(Direction::Southeast),
-                Move::Hook(Direction::Southwest),
-                Move::Hook(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Tile Chariot",
-            kanji: "瓦車",
-            additional_kanji: None,
-            romaji: "gasha",
-            promotion: Some("Running Tile"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Vertical Wolf",
-            kanji: "竪狼",
-            additional_kanji: None,
-            romaji: "shurō",
-            promotion: Some("Running Wolf"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Step(3, Direction::South),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Side Ox",
-            kanji: "横牛",
-            additional_kanji: None,
-            romaji: "ōgyū",
-            promotion: Some("Flying Ox"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::Southwest),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Kylin Horse",
-            kanji: "驢馬",
-            additional_kanji: None,
-            romaji: "roba",
-            promotion: Some("Pidgeon"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Flying Horse",
-            kanji: "馬麟",
-            additional_kanji: None,
-            romaji: "barin",
-            promotion: Some("Free King"),
-            moves: vec![
-                Move::Step(2, Direction::Northeast),
-                Move::Step(2, Direction::Southeast),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Violent Bear",
-            kanji: "猛熊",
-            additional_kanji: None,
-            romaji: "mōyū",
-            promotion: Some("Great Bear"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Angry Boar",
-            kanji: "嗔猪",
-            additional_kanji: None,
-            romaji: "shincho",
-            promotion: Some("Free Boar"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Evil Wolf",
-            kanji: "悪狼",
-            additional_kanji: None,
-            romaji: "akurō",
-            promotion: Some("Poisonous Wolf"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Wind Horse",
-            kanji: "風馬",
-            additional_kanji: None,
-            romaji: "fūma",
-            promotion: Some("Heavenly Horse"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(2, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Flying Kite Hawk",
-            kanji: "鶏飛",
-            additional_kanji: None,
-            romaji: "keihi",
-            promotion: Some("Hawk"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Old Monkey",
-            kanji: "古猿",
-            additional_kanji: None,
-            romaji: "koen",
-            promotion: Some("Mountain Witch"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Chinese Cock",
-            kanji: "淮鶏",
-            additional_kanji: None,
-            romaji: "waikei",
-            promotion: Some("Wizard Stork"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Northern Barbarian",
-            kanji: "北狄",
-            additional_kanji: None,
-            romaji: "hokuteki",
-            promotion: Some("Dove"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Southern Barbarian",
-            kanji: "南蛮",
-            additional_kanji: None,
-            romaji: "nanban",
-            promotion: Some("Golden Bird"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Western Barbarian",
-            kanji: "西戎",
-            additional_kanji: None,
-            romaji: "seijū",
-            promotion: Some("Lion Dog"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Eastern Barbarian",
-            kanji: "東夷",
-            additional_kanji: None,
-            romaji: "tōi",
-            promotion: Some("Lion"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Ferocious Deer",
-            kanji: "猛鹿",
-            additional_kanji: None,
-            romaji: "mōroku",
-            promotion: Some("Running Boar"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Fierce Wolf",
-            kanji: "猛狼",
-            additional_kanji: None,
-            romaji: "mōrō",
-            promotion: Some("Bear's Eyes"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Treacherous Fox",
-            kanji: "隠狐",
-            additional_kanji: None,
-            romaji: "inko",
-            promotion: Some("Mountain Bird"),
-            moves: vec![
-                Move::JumpThenRange(3, Direction::North),
-                Move::JumpThenRange(3, Direction::Northeast),
-                Move::JumpThenRange(3, Direction::Southeast),
-                Move::JumpThenRange(3, Direction::South),
-                Move::JumpThenRange(3, Direction::Southwest),
-                Move::JumpThenRange(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Middle Teacher",
-            kanji: "中師",
-            additional_kanji: None,
-            romaji: "chūshi",
-            promotion: None,
-            moves: vec![
-                Move::JumpOrRange(2, Direction::North),
-                Move::JumpOrRange(2, Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::JumpOrRange(2, Direction::South),
-                Move::Step(3, Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::JumpOrRange(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Phoenix Master",
-            kanji: "鵬師",
-            additional_kanji: None,
-            romaji: "hōshi",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::JumpOrRange(3, Direction::Northeast),
-                Move::Step(5, Direction::East),
-                Move::Step(5, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(5, Direction::Southwest),
-                Move::Step(5, Direction::West),
-                Move::JumpOrRange(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Earth Chariot",
-            kanji: "土車",
-            additional_kanji: None,
-            romaji: "dosha",
-            promotion: Some("Reed Bird"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Vermillion Sparrow",
-            kanji: "朱雀",
-            additional_kanji: None,
-            romaji: "suzaku",
-            promotion: Some("Divine Sparrow"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Blue Dragon",
-            kanji: "青龍",
-            additional_kanji: None,
-            romaji: "seiryū",
-            promotion: Some("Divine Dragon"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Enchanted Badger",
-            kanji: "変狸",
-            additional_kanji: None,
-            romaji: "henri",
-            promotion: Some("Pidgeon"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Cavalryman",
-            kanji: "騎兵",
-            additional_kanji: None,
-            romaji: "kihei",
-            promotion: Some("Horseman"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Kite Hawk Runner",
-            kanji: "鴟行",
-            additional_kanji: None,
-            romaji: "shigyō",
-            promotion: Some("Cloud Eagle"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Climbing Monkey",
-            kanji: "登猿",
-            additional_kanji: None,
-            romaji: "tōen",
-            promotion: Some("Ferocious Deer"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Cat Sword",
-            kanji: "猫刄",
-            additional_kanji: None,
-            romaji: "myōjin",
-            promotion: Some("Dragon Horse"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Swallow's Wings",
-            kanji: "燕羽",
-            additional_kanji: None,
-            romaji: "en'u",
-            promotion: Some("Swallow Runner"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Blind Monkey",
-            kanji: "盲猿",
-            additional_kanji: None,
-            romaji: "mōen",
-            promotion: Some("Flying Deer"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Blind Tiger",
-            kanji: "盲虎",
-            additional_kanji: None,
-            romaji: "mōko",
-            promotion: Some("Flying Deer"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Ox Chariot",
-            kanji: "牛車",
-            additional_kanji: None,
-            romaji: "gissha",
-            promotion: Some("Forward Ox"),
-            moves: vec![Move::Range(Direction::North)],
-        },
-        Face {
-            name: "Side Rook",
-            kanji: "横飛",
-            additional_kanji: None,
-            romaji: "ōhi",
-            promotion: Some("Side Dragon"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Blind Bear",
-            kanji: "盲熊",
-            additional_kanji: None,
-            romaji: "mōyū",
-            promotion: Some("Flying Deer"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Old Rat",
-            kanji: "老鼠",
-            additional_kanji: None,
-            romaji: "rōso",
-            promotion: Some("Mockingbird"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Square Mover",
-            kanji: "方行",
-            additional_kanji: None,
-            romaji: "hōgyō",
-            promotion: Some("Strong Chariot"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Coiled Serpent",
-            kanji: "蟠蛇",
-            additional_kanji: None,
-            romaji: "banja",
-            promotion: Some("Coiled Dragon"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Reclining Dragon",
-            kanji: "臥龍",
-            additional_kanji: None,
-            romaji: "garyū",
-            promotion: Some("Great Dragon"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Free Eagle",
-            kanji: "奔鷲",
-            additional_kanji: None,
-            romaji: "honjū",
-            promotion: None,
-            moves: vec![Move::LimitedLion],
-        },
-        Face {
-            name: "Lion Hawk",
-            kanji: "獅鷹",
-            additional_kanji: None,
-            romaji: "shiō",
-            promotion: None,
-            moves: vec![
-                Move::FullLion,
-                Move::Jump(2, Direction::North),
-                Move::JumpOrRange(2, Direction::Northeast),
-                Move::Jump(2, Direction::East),
-                Move::JumpOrRange(2, Direction::Southeast),
-                Move::Jump(2, Direction::South),
-                Move::JumpOrRange(2, Direction::Southwest),
-                Move::Jump(2, Direction::West),
-                Move::JumpOrRange(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Chariot Soldier",
-            kanji: "車兵",
-            additional_kanji: None,
-            romaji: "shahei",
-            promotion: Some("Heavenly Tetrarchs"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Side Soldier",
-            kanji: "横兵",
-            additional_kanji: None,
-            romaji: "ōhei",
-            promotion: Some("Fire Ox"),
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Vertical Soldier",
-            kanji: "竪兵",
-            additional_kanji: None,
-            romaji: "shuhei",
-            promotion: Some("Chariot Soldier"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Wind General",
-            kanji: "風将",
-            additional_kanji: None,
-            romaji: "fūshō",
-            promotion: Some("Violent Wind"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "River General",
-            kanji: "川将",
-            additional_kanji: None,
-            romaji: "senshō",
-            promotion: Some("Hui River"),
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Mountain General",
-            kanji: "山将",
-            additional_kanji: None,
-            romaji: "sanshō",
-            promotion: Some("Mount Tai"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Standard Bearer",
-            kanji: "前旗",
-            additional_kanji: None,
-            romaji: "zenki",
-            promotion: Some("Great Standard"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(3, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Horse Soldier",
-            kanji: "馬兵",
-            additional_kanji: None,
-            romaji: "bahei",
-            promotion: Some("Running Horse"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Wood General",
-            kanji: "木将",
-            additional_kanji: None,
-            romaji: "mokushō",
-            promotion: Some("White Elephant"),
-            moves: vec![
-                Move::Step(2, Direction::Northeast),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Ox Soldier",
-            kanji: "牛兵",
-            additional_kanji: None,
-            romaji: "gyūhei",
-            promotion: Some("Running Ox"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Earth General",
-            kanji: "土将",
-            additional_kanji: None,
-            romaji: "doshō",
-            promotion: Some("White Elephant"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::South),
-            ],
-        },
-        Face {
-            name: "Boar Soldier",
-            kanji: "猪兵",
-            additional_kanji: None,
-            romaji: "chohei",
-            promotion: Some("Running Boar"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Stone General",
-            kanji: "石将",
-            additional_kanji: None,
-            romaji: "sekishō",
-            promotion: Some("White Elephant"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Leopard Soldier",
-            kanji: "豹兵",
-            additional_kanji: None,
-            romaji: "hyōhei",
-            promotion: Some("Running Leopard"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Tile General",
-            kanji: "瓦将",
-            additional_kanji: None,
-            romaji: "gashō",
-            promotion: Some("White Elephant"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Bear Soldier",
-            kanji: "熊兵",
-            additional_kanji: None,
-            romaji: "yūhei",
-            promotion: Some("Strong Bear"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Iron General",
-            kanji: "鉄将",
-            additional_kanji: None,
-            romaji: "tesshō",
-            promotion: Some("White Elephant"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Standard",
-            kanji: "大旗",
-            additional_kanji: None,
-            romaji: "daiki",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(3, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Teacher",
-            kanji: "大師",
-            additional_kanji: None,
-            romaji: "daishi",
-            promotion: None,
-            moves: vec![
-                Move::JumpOrRange(3, Direction::North),
-                Move::JumpOrRange(3, Direction::Northeast),
-                Move::Step(5, Direction::East),
-                Move::Step(5, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(5, Direction::Southwest),
-                Move::Step(5, Direction::West),
-                Move::JumpOrRange(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Right Chariot",
-            kanji: "右車",
-            additional_kanji: None,
-            romaji: "usha",
-            promotion: Some("Right Iron Chariot"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Left Chariot",
-            kanji: "左車",
-            additional_kanji: None,
-            romaji: "sasha",
-            promotion: Some("Left Iron Chariot"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Side Monkey",
-            kanji: "横猿",
-            additional_kanji: None,
-            romaji: "ōen",
-            promotion: Some("Side Soldier"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Vertical Mover",
-            kanji: "竪行",
-            additional_kanji: None,
-            romaji: "shugyō",
-            promotion: Some("Flying Ox"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Flying Ox",
-            kanji: "飛牛",
-            additional_kanji: None,
-            romaji: "higyū",
-            promotion: Some("Fire Ox"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Crossbowman",
-            kanji: "弩兵",
-            additional_kanji: None,
-            romaji: "dohei",
-            promotion: Some("Crossbow General"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(5, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(5, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Vertical Puppy",
-            kanji: "竪狗",
-            additional_kanji: None,
-            romaji: "shuku",
-            promotion: Some("Leopard King"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Vertical Horse",
-            kanji: "竪馬",
-            additional_kanji: None,
-            romaji: "shuba",
-            promotion: Some("Dragon Horse"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Cannon Soldier",
-            kanji: "炮兵",
-            additional_kanji: None,
-            romaji: "hōhei",
-            promotion: Some("Cannon General"),
-            moves: vec![
-                Move::Step(7, Direction::North),
-                Move::Step(5, Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::West),
-                Move::Step(5, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Dragon Horse",
-            kanji: "龍馬",
-            additional_kanji: None,
-            romaji: "ryūme",
-            promotion: Some("Horned Falcon"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Dragon King",
-            kanji: "龍王",
-            additional_kanji: None,
-            romaji: "ryūō",
-            promotion: Some("Soaring Eagle"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Sword Soldier",
-            kanji: "刀兵",
-            additional_kanji: None,
-            romaji: "tōhei",
-            promotion: Some("Sword General"),
-            moves: vec![
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Horned Falcon",
-            kanji: "角鷹",
-            additional_kanji: None,
-            romaji: "kakuō",
-            promotion: Some("Great Hawk"),
-            moves: vec![
-                Move::JumpOrRange(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Soaring Eagle",
-            kanji: "飛鷲",
-            additional_kanji: None,
-            romaji: "hijū",
-            promotion: Some("Great Eagle"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::JumpOrRange(2, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::JumpOrRange(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Spear Soldier",
-            kanji: "鎗兵",
-            additional_kanji: None,
-            romaji: "sōhei",
-            promotion: Some("Spear General"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Vertical Leopard",
-            kanji: "竪豹",
-            additional_kanji: None,
-            romaji: "shuhyō",
-            promotion: Some("Great Leopard"),
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Fierce Tiger",
-            kanji: "猛虎",
-            additional_kanji: None,
-            romaji: "mōko",
-            promotion: Some("Great Tiger"),
-            moves: vec![Move::Range(Direction::North)],
-        },
-        Face {
-            name: "Archer",
-            kanji: "弓兵",
-            additional_kanji: None,
-            romaji: "kyūhei",
-            promotion: Some("Archery General"),
-            moves: vec![
-                Move::Step(5, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Barking Dog",
-            kanji: "吼犬",
-            additional_kanji: None,
-            romaji: "kōken",
-            promotion: Some("Lion Dog"),
-            moves: vec![
-                Move::JumpOrRange(3, Direction::North),
-                Move::JumpOrRange(3, Direction::Northeast),
-                Move::JumpOrRange(3, Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::JumpOrRange(3, Direction::South),
-                Move::Step(3, Direction::Southwest),
-                Move::JumpOrRange(3, Direction::West),
-                Move::JumpOrRange(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Lion Dog",
-            kanji: "狛犬",
-            additional_kanji: None,
-            romaji: "komainu",
-            promotion: Some("Great Elephant"),
-            moves: vec![
-                Move::JumpOrRange(3, Direction::North),
-                Move::JumpOrRange(3, Direction::Northeast),
-                Move::JumpOrRange(3, Direction::East),
-                Move::JumpOrRange(3, Direction::Southeast),
-                Move::JumpOrRange(3, Direction::South),
-                Move::JumpOrRange(3, Direction::Southwest),
-                Move::JumpOrRange(3, Direction::West),
-                Move::JumpOrRange(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Dog",
-            kanji: "犬",
-            additional_kanji: None,
-            romaji: "inu",
-            promotion: Some("Sundry General"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Go Between",
-            kanji: "仲人",
-            additional_kanji: None,
-            romaji: "chūnin",
-            promotion: Some("Drunken Elephant"),
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::South),
-            ],
-        },
-        Face {
-            name: "Pawn",
-            kanji: "歩兵",
-            additional_kanji: None,
-            romaji: "fuhyō",
-            promotion: Some("Gold General"),
-            moves: vec![Move::Step(1, Direction::North)],
-        },
-        Face {
-            name: "Free Insect",
-            kanji: "奔翅",
-            additional_kanji: None,
-            romaji: "honshi",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::FlyingJump(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(3, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(3, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::FlyingJump(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Tapir",
-            kanji: "大獏",
-            additional_kanji: None,
-            romaji: "daibaku",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::JumpOrRange(3, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::JumpOrRange(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Primeval Dragon",
-            kanji: "元龍",
-            additional_kanji: None,
-            romaji: "genryū",
-            promotion: None,
-            moves: vec![
-                Move::FlyingJump(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::FlyingJump(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Heavenly Tetrarchs",
-            kanji: "四天王",
-            additional_kanji: None,
-            romaji: "shitennō",
-            promotion: None,
-            moves: vec![
-                Move::JumpThenRange(2, Direction::North),
-                Move::JumpThenRange(2, Direction::Northeast),
-                Move::JumpThenRange(2, Direction::East),
-                Move::JumpThenRange(2, Direction::Southeast),
-                Move::JumpThenRange(2, Direction::South),
-                Move::JumpThenRange(2, Direction::Southwest),
-                Move::JumpThenRange(2, Direction::West),
-                Move::JumpThenRange(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Hawk",
-            kanji: "大鷹",
-            additional_kanji: None,
-            romaji: "daiō",
-            promotion: None,
-            moves: vec![
-                Move::JumpThenRange(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Elephant",
-            kanji: "大象",
-            additional_kanji: None,
-            romaji: "taizō",
-            promotion: None,
-            moves: vec![
-                Move::FlyingJump(Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::FlyingJump(Direction::East),
-                Move::FlyingJump(Direction::Southeast),
-                Move::FlyingJump(Direction::South),
-                Move::FlyingJump(Direction::Southwest),
-                Move::FlyingJump(Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Fire Ox",
-            kanji: "火牛",
-            additional_kanji: None,
-            romaji: "kagyū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Strong Bear",
-            kanji: "強熊",
-            additional_kanji: None,
-            romaji: "kyōyū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Right Phoenix",
-            kanji: "右鵰",
-            additional_kanji: None,
-            romaji: "ushū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Step(5, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::Southwest),
-                Move::Step(5, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Leopard",
-            kanji: "走豹",
-            additional_kanji: None,
-            romaji: "sōhyō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Thunderous Running",
-            kanji: "雷走",
-            additional_kanji: None,
-            romaji: "raisō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(4, Direction::East),
-                Move::Step(4, Direction::South),
-                Move::Step(4, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Monsoon Demon",
-            kanji: "霖鬼",
-            additional_kanji: None,
-            romaji: "rinki",
-            promotion: None,
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::JumpThenRange(2, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::JumpThenRange(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Boar",
-            kanji: "奔猪",
-            additional_kanji: None,
-            romaji: "honcho",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Dog",
-            kanji: "奔犬",
-            additional_kanji: None,
-            romaji: "honken",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Ox",
-            kanji: "走牛",
-            additional_kanji: None,
-            romaji: "sōgyū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Colt",
-            kanji: "大駒",
-            additional_kanji: None,
-            romaji: "daiku",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Horseman",
-            kanji: "騎士",
-            additional_kanji: None,
-            romaji: "kishi",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Fire",
-            kanji: "奔火",
-            additional_kanji: None,
-            romaji: "honka",
-            promotion: None,
-            moves: vec![
-                Move::Step(5, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(5, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Cannon Chariot",
-            kanji: "炮車",
-            additional_kanji: None,
-            romaji: "hōsha",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Deer",
-            kanji: "奔鹿",
-            additional_kanji: None,
-            romaji: "honroku",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Dragon",
-            kanji: "奔龍",
-            additional_kanji: None,
-            romaji: "honryū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Flying Crocodile",
-            kanji: "飛鰐",
-            additional_kanji: None,
-            romaji: "higaku",
-            promotion: None,
-            moves: vec![
-                Move::FlyingCapture(Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::FlyingCapture(Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::FlyingCapture(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::FlyingCapture(Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Strong Chariot",
-            kanji: "強車",
-            additional_kanji: None,
-            romaji: "kyōsha",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Divine Tiger",
-            kanji: "神虎",
-            additional_kanji: None,
-            romaji: "shinko",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Divine Dragon",
-            kanji: "神龍",
-            additional_kanji: None,
-            romaji: "shinryū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Divine Turtle",
-            kanji: "神亀",
-            additional_kanji: None,
-            romaji: "shinki",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Divine Sparrow",
-            kanji: "神雀",
-            additional_kanji: None,
-            romaji: "shinjaku",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Snake",
-            kanji: "奔蛇",
-            additional_kanji: None,
-            romaji: "honja",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Free Wolf",
-            kanji: "奔狼",
-            additional_kanji: None,
-            romaji: "honrō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Tiger",
-            kanji: "大虎",
-            additional_kanji: None,
-            romaji: "daiko",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Right Dog",
-            kanji: "右犬",
-            additional_kanji: None,
-            romaji: "uken",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Left Dog",
-            kanji: "左犬",
-            additional_kanji: None,
-            romaji: "saken",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::South),
-            ],
-        },
-        Face {
-            name: "Free Bear",
-            kanji: "奔熊",
-            additional_kanji: None,
-            romaji: "hon'yū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Tiger",
-            kanji: "奔虎",
-            additional_kanji: None,
-            romaji: "honko",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Boar",
-            kanji: "走猪",
-            additional_kanji: None,
-            romaji: "sōcho",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Free Leopard",
-            kanji: "奔豹",
-            additional_kanji: None,
-            romaji: "honpyō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Heavenly Horse",
-            kanji: "天馬",
-            additional_kanji: None,
-            romaji: "temma",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::KnightForward,
-                Move::KnightBackward,
-            ],
-        },
-        Face {
-            name: "Spear General",
-            kanji: "鎗将",
-            additional_kanji: None,
-            romaji: "sōshō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(3, Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Step(3, Direction::West),
-            ],
-        },
-        Face {
-            name: "Great Leopard",
-            kanji: "大豹",
-            additional_kanji: None,
-            romaji: "daihyō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Flying Deer",
-            kanji: "飛鹿",
-            additional_kanji: None,
-            romaji: "hiroku",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Right Army",
-            kanji: "右軍",
-            additional_kanji: None,
-            romaji: "ugun",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Left Army",
-            kanji: "左軍",
-            additional_kanji: None,
-            romaji: "sagun",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Beastly Bird",
-            kanji: "獣鳥",
-            additional_kanji: None,
-            romaji: "jūchō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "The Birds",
-            kanji: "獣鳥",
-            additional_kanji: None,
-            romaji: "kinchō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Swallow Runner",
-            kanji: "燕行",
-            additional_kanji: None,
-            romaji: "engyō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-            ],
-        },
-        Face {
-            name: "Buddhist Spirit",
-            kanji: "法性",
-            additional_kanji: None,
-            romaji: "hōsei",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-                Move::FullLion,
-            ],
-        },
-        Face {
-            name: "Teaching King",
-            kanji: "教王",
-            additional_kanji: None,
-            romaji: "kyōō",
-            promotion: None,
-            moves: vec![
-                Move::FlyingJump(Direction::North),
-                Move::FlyingJump(Direction::Northeast),
-                Move::FlyingJump(Direction::East),
-                Move::FlyingJump(Direction::Southeast),
-                Move::FlyingJump(Direction::South),
-                Move::FlyingJump(Direction::Southwest),
-                Move::FlyingJump(Direction::West),
-                Move::FlyingJump(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Unicorn",
-            kanji: "大鱗",
-            additional_kanji: None,
-            romaji: "dairin",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(5, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Step(5, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Furious Fiend",
-            kanji: "奮迅",
-            additional_kanji: None,
-            romaji: "funjin",
-            promotion: None,
-            moves: vec![
-                Move::JumpOrRange(3, Direction::North),
-                Move::JumpOrRange(3, Direction::Northeast),
-                Move::JumpOrRange(3, Direction::East),
-                Move::JumpOrRange(3, Direction::Southeast),
-                Move::JumpOrRange(3, Direction::South),
-                Move::JumpOrRange(3, Direction::Southwest),
-                Move::JumpOrRange(3, Direction::West),
-                Move::JumpOrRange(3, Direction::Northwest),
-                Move::FullLion,
-            ],
-        },
-        Face {
-            name: "Leopard King",
-            kanji: "豹王",
-            additional_kanji: None,
-            romaji: "hyōō",
-            promotion: None,
-            moves: vec![
-                Move::Step(5, Direction::North),
-                Move::Step(5, Direction::Northeast),
-                Move::Step(5, Direction::East),
-                Move::Step(5, Direction::Southeast),
-                Move::Step(5, Direction::South),
-                Move::Step(5, Direction::Southwest),
-                Move::Step(5, Direction::West),
-                Move::Step(5, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Swan's Wings",
-            kanji: "鴻翼",
-            additional_kanji: None,
-            romaji: "kōyoko",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(3, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Left Iron Chariot",
-            kanji: "鉄車",
-            additional_kanji: Some("左"),
-            romaji: "satessha",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-            ],
-        },
-        Face {
-            name: "Right Iron Chariot",
-            kanji: "鉄車",
-            additional_kanji: Some("右"),
-            romaji: "utessha",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Forward Ox",
-            kanji: "歬牛",
-            additional_kanji: None,
-            romaji: "sengyū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Wind Turtle",
-            kanji: "風鼈",
-            additional_kanji: None,
-            romaji: "fūbetsu",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Tile",
-            kanji: "走瓦",
-            additional_kanji: None,
-            romaji: "sōga",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Reed Bird",
-            kanji: "𦬨鳥",
-            additional_kanji: None,
-            romaji: "shakuchō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-            ],
-        },
-        Face {
-            name: "Playful Dove",
-            kanji: "遊䳇",
-            additional_kanji: None,
-            romaji: "yūmo",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(5, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(5, Direction::West),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Copper Elephant",
-            kanji: "銅象",
-            additional_kanji: None,
-            romaji: "dōzō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Walking Heron",
-            kanji: "歩䳲",
-            additional_kanji: None,
-            romaji: "fushin",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(2, Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::West),
-                Move::Step(2, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Tiger Soldier",
-            kanji: "虎兵",
-            additional_kanji: None,
-            romaji: "kohei",
-            promotion: None,
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Sinewy Eagle",
-            kanji: "勁鷲",
-            additional_kanji: None,
-            romaji: "keijū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Dragon",
-            kanji: "走龍",
-            additional_kanji: None,
-            romaji: "sōryū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(5, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Four Heavens",
-            kanji: "四天",
-            additional_kanji: None,
-            romaji: "shiten",
-            promotion: None,
-            moves: vec![
-                Move::Step(4, Direction::North),
-                Move::Step(4, Direction::Northeast),
-                Move::Step(4, Direction::East),
-                Move::Step(4, Direction::Southeast),
-                Move::Step(4, Direction::South),
-                Move::Step(4, Direction::Southwest),
-                Move::Step(4, Direction::West),
-                Move::Step(4, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Elephant King",
-            kanji: "象王",
-            additional_kanji: None,
-            romaji: "zōō",
-            promotion: None,
-            moves: vec![
-                Move::Step(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Mount Tai",
-            kanji: "泰山",
-            additional_kanji: None,
-            romaji: "taizan",
-            promotion: None,
-            moves: vec![
-                Move::Step(5, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(5, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::Southwest),
-                Move::Step(5, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Hui River",
-            kanji: "淮川",
-            additional_kanji: None,
-            romaji: "waisen",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Violent Wind",
-            kanji: "暴風",
-            additional_kanji: None,
-            romaji: "bōfū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Rooster",
-            kanji: "奔鶏",
-            additional_kanji: None,
-            romaji: "honkei",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(2, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(2, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Ox",
-            kanji: "奔牛",
-            additional_kanji: None,
-            romaji: "hongyū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Horse",
-            kanji: "奔馬",
-            additional_kanji: None,
-            romaji: "honba",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Whale",
-            kanji: "大鯨",
-            additional_kanji: None,
-            romaji: "daigei",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Free Pig",
-            kanji: "奔豚",
-            additional_kanji: None,
-            romaji: "honton",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(2, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Crossbow General",
-            kanji: "弓将",
-            additional_kanji: None,
-            romaji: "kyūshō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(5, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(5, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Cannon General",
-            kanji: "炮将",
-            additional_kanji: None,
-            romaji: "hōshō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Range(Direction::South),
-                Move::Step(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Archery General",
-            kanji: "弓将",
-            additional_kanji: None,
-            romaji: "kyūshō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(5, Direction::Northeast),
-                Move::Step(3, Direction::East),
-                Move::Step(2, Direction::South),
-                Move::Step(3, Direction::West),
-                Move::Step(5, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Mountain Bird",
-            kanji: "山鶻",
-            additional_kanji: None,
-            romaji: "sankotsu",
-            promotion: None,
-            moves: vec![
-                Move::JumpThenRange(4, Direction::North),
-                Move::JumpThenRange(4, Direction::Northeast),
-                Move::JumpThenRange(4, Direction::East),
-                Move::JumpThenRange(4, Direction::Southeast),
-                Move::JumpThenRange(4, Direction::South),
-                Move::JumpThenRange(4, Direction::Southwest),
-                Move::JumpThenRange(4, Direction::West),
-                Move::JumpThenRange(4, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Running Boar",
-            kanji: "走猪",
-            additional_kanji: None,
-            romaji: "sōcho",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Sword General",
-            kanji: "刀将",
-            additional_kanji: None,
-            romaji: "tōshō",
-            promotion: None,
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Mockingbird",
-            kanji: "古寺時鳥",
-            additional_kanji: None,
-            romaji: "jichō",
-            promotion: None,
-            moves: vec![
-                Move::Step(3, Direction::North),
-                Move::Step(3, Direction::Northeast),
-                Move::Step(1, Direction::South),
-                Move::Step(3, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Coiled Dragon",
-            kanji: "蟠龍",
-            additional_kanji: None,
-            romaji: "banryū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-            ],
-        },
-        Face {
-            name: "Bear's Eyes",
-            kanji: "熊眼",
-            additional_kanji: None,
-            romaji: "yūgan",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Mountain Witch",
-            kanji: "山母",
-            additional_kanji: None,
-            romaji: "sanbo",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Flying Hawk",
-            kanji: "飛鷹",
-            additional_kanji: None,
-            romaji: "hiyō",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Poisonous Wolf",
-            kanji: "毒狼",
-            additional_kanji: None,
-            romaji: "dokurō",
-            promotion: None,
-            moves: vec![
-                Move::Step(1, Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::Southeast),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::Southwest),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Spirit Turtle",
-            kanji: "霊亀",
-            additional_kanji: None,
-            romaji: "reiki",
-            promotion: None,
-            moves: vec![
-                Move::JumpOrRange(3, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::JumpOrRange(3, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::JumpOrRange(3, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::JumpOrRange(3, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Treasure Turtle",
-            kanji: "宝亀",
-            additional_kanji: None,
-            romaji: "hōki",
-            promotion: None,
-            moves: vec![
-                Move::JumpOrRange(2, Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::JumpOrRange(2, Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::JumpOrRange(2, Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::JumpOrRange(2, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Bear",
-            kanji: "大熊",
-            additional_kanji: None,
-            romaji: "daiyū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::South),
-                Move::Step(1, Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Sundry General",
-            kanji: "雜将",
-            additional_kanji: None,
-            romaji: "suishō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Wizard Stork",
-            kanji: "仙而鷦",
-            additional_kanji: None,
-            romaji: "senkaku",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::South),
-                Move::Range(Direction::West),
-                Move::Range(Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Hawk",
-            kanji: "延鷹",
-            additional_kanji: None,
-            romaji: "en'yō",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::Step(1, Direction::Northeast),
-                Move::Step(1, Direction::East),
-                Move::Step(1, Direction::West),
-                Move::Step(1, Direction::Northwest),
-            ],
-        },
-        Face {
-            name: "Great Eagle",
-            kanji: "大鷲",
-            additional_kanji: None,
-            romaji: "daijū",
-            promotion: None,
-            moves: vec![
-                Move::Range(Direction::North),
-                Move::JumpThenRange(2, Direction::Northeast),
-                Move::Range(Direction::East),
-                Move::Range(Direction::Southeast),
-                Move::Range(Direction::South),
-                Move::Range(Direction::Southwest),
-                Move::Range(Direction::West),
-                Move::JumpThenRange(2, Direction::Northwest),
-            ],
-        },
-    ]
+    static FACES: OnceLock<Vec<Face>> = OnceLock::new();
+    FACES
+        .get_or_init(|| parse_faces(TAIKYOKU).expect("the built-in face table is invalid"))
+        .clone()
+}
+
+// Reads faces in the format of `data/taikyoku.toml`, or the same structure as
+// JSON when the file has a `.json` extension.
+pub fn load_faces(path: impl AsRef<Path>) -> Result<Vec<Face>, FormatError> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path)?;
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        let FaceTable { faces } = serde_json::from_str(&text)?;
+        Ok(faces)
+    } else {
+        parse_faces(&text)
+    }
+}
+
+pub fn parse_faces(toml: &str) -> Result<Vec<Face>, FormatError> {
+    let FaceTable { faces } = toml::from_str(toml)?;
+    Ok(faces)
 }
@@ -1,29 +1,73 @@
+mod betza;
+mod card;
+mod catalogue;
+mod checkpoint;
+mod convergence;
+mod diagram;
+mod export;
 mod face;
+mod progress;
+mod promotion;
+mod record;
 mod simulate;
+mod validate;
+mod variant;
 
 use std::fmt::Display;
 
-pub use simulate::{simulate_n, simulate_n_par, Simulation};
+pub use betza::{parse_betza, to_betza, Betza, BetzaError};
+pub use card::{card_file_name, face_card};
+pub use catalogue::{Catalogue, LookupError};
+pub use checkpoint::{
+    load_checkpoint, resume_from_checkpoint, save_checkpoint, simulate_with_checkpoints,
+    ResumeError,
+};
+pub use convergence::{
+    simulate_until_converged, simulate_until_converged_with, Convergence, ConvergenceCriteria,
+};
+pub use diagram::{Charset, Diagram};
+pub use export::FormatError;
+pub use face::{faces, load_faces, parse_faces, Direction, Face, Move, Rank};
+pub use progress::{simulate_with_progress, Progress, ProgressTracker};
+pub use promotion::{PromotionError, PromotionGraph};
+pub use record::{MergeError, SimulationRecord};
+pub use simulate::{
+    default_batch_size, extend_statistics_par, occupancy_model, simulate, simulate_in_batches,
+    simulate_n, simulate_n_par, simulate_n_par_seeded, simulate_n_par_statistics,
+    simulate_n_seeded, simulate_n_statistics, simulate_with_rng, trial_rng, Board, BoardError,
+    Estimate, FixedDensity, GamePhase, Grid, Mobility, OccupancyError, OccupancyModel, RankMix,
+    Ranked, Replay, Setup, Simulation, SimulationStatistics, Square, Territorial, Uniform,
+    MAX_BOARD_SIZE,
+};
+pub use validate::{validate_faces, Problem};
+pub use variant::{
+    parse_variant, starting_array, variant, variants, StartingArray, Variant, VariantError,
+};
 
-use face::{faces, Face, Move};
-
-pub struct ValueAssignments(Vec<(Face, OpaqueNumber)>);
+pub struct ValueAssignments(pub Vec<(Face, OpaqueNumber)>);
 
 pub struct OpaqueNumber(u64);
 
 impl Display for OpaqueNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let OpaqueNumber(n) = self;
-        
+
         write!(f, "{}", n / 2)?;
         if n % 2 == 1 {
             write!(f, ".5")?;
         }
-        
+
         Ok(())
     }
 }
 
+impl ValueAssignments {
+    pub fn retain(&mut self, keep: impl Fn(&Face) -> bool) {
+        let ValueAssignments(assignments) = self;
+        assignments.retain(|(face, _)| keep(face));
+    }
+}
+
 fn round_to_nearest_half(x: f64) -> OpaqueNumber {
     OpaqueNumber((2.0 * x).round() as u64)
 }
@@ -31,7 +75,7 @@ fn round_to_nearest_half(x: f64) -> OpaqueNumber {
 impl Display for ValueAssignments {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let ValueAssignments(assignments) = self;
-        
+
         writeln!(f, "Value Assignments:")?;
         for (face, points) in assignments {
             writeln!(f, "\t{}: {}", face.name, points)?;
@@ -41,60 +85,193 @@ impl Display for ValueAssignments {
     }
 }
 
-pub fn assign_values_to_faces(simulation: &Simulation) -> ValueAssignments {
-    let assignments = faces()
-        .into_iter()
+pub fn assign_values_to_faces(faces: &[Face], simulation: &Simulation) -> ValueAssignments {
+    assign_values_to_faces_with(faces, simulation, assign_value_to_face)
+}
+
+pub fn assign_values_to_faces_with(
+    faces: &[Face],
+    simulation: &Simulation,
+    value: impl Fn(&Simulation, &Face) -> f64,
+) -> ValueAssignments {
+    let assignments = faces
+        .iter()
         .map(|face| {
-            let value = round_to_nearest_half(assign_value_to_face(&simulation, &face));
-            (face, value)
+            let value = round_to_nearest_half(value(simulation, face));
+            (face.clone(), value)
         })
         .collect();
     ValueAssignments(assignments)
 }
 
+pub struct ValueEstimates(pub Vec<(Face, Estimate)>);
+
+impl Display for ValueEstimates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ValueEstimates(estimates) = self;
+
+        writeln!(f, "Value Estimates (95% confidence):")?;
+        for (face, estimate) in estimates {
+            writeln!(f, "\t{}: {}", face.name, estimate)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ValueEstimates {
+    pub fn retain(&mut self, keep: impl Fn(&Face) -> bool) {
+        let ValueEstimates(estimates) = self;
+        estimates.retain(|(face, _)| keep(face));
+    }
+}
+
+// Face values from runs on several boards, one column per board.
+pub struct BoardComparison {
+    pub boards: Vec<(String, Board)>,
+    pub values: Vec<(Face, Vec<f64>)>,
+}
+
+impl BoardComparison {
+    // `runs` holds each board's averaged simulation, already divided by the
+    // value of the reference face on that board.
+    pub fn new(
+        faces: &[Face],
+        runs: &[(String, Board, Simulation)],
+        value: impl Fn(&Simulation, &Face) -> f64,
+    ) -> BoardComparison {
+        let boards = runs
+            .iter()
+            .map(|(name, board, _)| (name.clone(), *board))
+            .collect();
+        let values = faces
+            .iter()
+            .map(|face| {
+                let values = runs.iter().map(|(_, _, sim)| value(sim, face)).collect();
+                (face.clone(), values)
+            })
+            .collect();
+        BoardComparison { boards, values }
+    }
+
+    pub fn retain(&mut self, keep: impl Fn(&Face) -> bool) {
+        self.values.retain(|(face, _)| keep(face));
+    }
+}
+
+impl Display for BoardComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .values
+            .iter()
+            .map(|(face, _)| face.name.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        write!(f, "{:width$}", "")?;
+        for (name, _) in &self.boards {
+            write!(f, " {name:>12}")?;
+        }
+        writeln!(f)?;
+        for (face, values) in &self.values {
+            write!(f, "{:width$}", face.name)?;
+            for value in values {
+                write!(f, " {value:>12.2}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn estimate_values_of_faces(
+    faces: &[Face],
+    statistics: &SimulationStatistics,
+) -> ValueEstimates {
+    let estimates = faces
+        .iter()
+        .map(|face| {
+            let estimate = statistics.estimate(|sim| assign_value_to_face(sim, face));
+            (face.clone(), estimate)
+        })
+        .collect();
+    ValueEstimates(estimates)
+}
+
+pub fn estimate_relative_values_of_faces(
+    faces: &[Face],
+    statistics: &SimulationStatistics,
+    reference: impl Fn(&Simulation) -> f64,
+) -> ValueEstimates {
+    estimate_relative_values_of_faces_with(faces, statistics, reference, assign_value_to_face)
+}
+
+pub fn estimate_relative_values_of_faces_with(
+    faces: &[Face],
+    statistics: &SimulationStatistics,
+    reference: impl Fn(&Simulation) -> f64,
+    value: impl Fn(&Simulation, &Face) -> f64,
+) -> ValueEstimates {
+    let estimates = faces
+        .iter()
+        .map(|face| {
+            let estimate = statistics.estimate_ratio(|sim| value(sim, face), &reference);
+            (face.clone(), estimate)
+        })
+        .collect();
+    ValueEstimates(estimates)
+}
+
 pub fn assign_value_to_face(simulation: &Simulation, face: &Face) -> f64 {
+    assign_weighted_value_to_face(simulation, face, 1.0)
+}
+
+// Values captures at `capture_weight` times a quiet move, so that attacking
+// power can count for more (or less) than plain mobility.
+pub fn assign_weighted_value_to_face(
+    simulation: &Simulation,
+    face: &Face,
+    capture_weight: f64,
+) -> f64 {
     face.moves
         .iter()
-        .map(|m| assign_value_to_move(simulation, m))
+        .map(|m| assign_weighted_value_to_move(simulation, m, face.rank, capture_weight))
         .sum()
 }
 
-pub fn assign_value_to_move(simulation: &Simulation, m: &Move) -> f64 {
+// `rank` is that of the face making the move, which only range captures use.
+pub fn assign_value_to_move(simulation: &Simulation, m: &Move, rank: Rank) -> f64 {
+    assign_weighted_value_to_move(simulation, m, rank, 1.0)
+}
+
+pub fn assign_weighted_value_to_move(
+    simulation: &Simulation,
+    m: &Move,
+    rank: Rank,
+    capture_weight: f64,
+) -> f64 {
+    mobility_of_move(&simulation.quiet, m, rank)
+        + capture_weight * mobility_of_move(&simulation.captures, m, rank)
+}
+
+fn mobility_of_move(mobility: &Mobility, m: &Move, rank: Rank) -> f64 {
     match m {
-        Move::Step(n, direction) if direction.is_orthogonal() => {
-            simulation.orthogonal_steps[*n as usize]
-        }
-        Move::Step(n, _) => simulation.diagonal_steps[*n as usize],
-        Move::Range(direction) if direction.is_orthogonal() => simulation.orthogonal_range,
-        Move::Range(_) => simulation.diagonal_range,
-        Move::Jump(n, direction) if direction.is_orthogonal() => {
-            simulation.orthogonal_jumps[*n as usize]
-        }
-        Move::Jump(n, _) => simulation.diagonal_jumps[*n as usize],
-        Move::Dove => simulation.dove,
-        Move::KnightForward => simulation.knight_jump * 2.0,
-        Move::KnightBackward => simulation.knight_jump * 2.0,
-        Move::Hook(direction) if direction.is_orthogonal() => simulation.orthogonal_hook,
-        Move::Hook(_) => simulation.diagonal_hook,
-        Move::FullLion => simulation.full_lion,
-        Move::LimitedLion => simulation.limited_lion,
-        Move::JumpThenRange(n, direction) if direction.is_orthogonal() => {
-            simulation.orthogonal_jump_then_range
-        }
-        Move::JumpThenRange(n, _) => simulation.diagonal_jump_then_range,
-        Move::FlyingJump(direction) if direction.is_orthogonal() => {
-            simulation.orthogonal_flying_jump
-        }
-        Move::FlyingJump(_) => simulation.diagonal_flying_jump,
-        Move::FlyingCapture(direction) if direction.is_orthogonal() => {
-            simulation.orthogonal_flying_capture
-        }
-        Move::FlyingCapture(_) => simulation.diagonal_flying_capture,
-        Move::JumpOrRange(n, direction) if direction.is_orthogonal() => {
-            simulation.orthogonal_jumps[*n as usize] + simulation.orthogonal_range
-        }
-        Move::JumpOrRange(n, _) => {
-            simulation.diagonal_jumps[*n as usize] + simulation.diagonal_range
+        Move::Step(n, direction) => mobility.steps[direction.index()][*n as usize],
+        Move::Range(direction) => mobility.range[direction.index()],
+        Move::Jump(n, direction) => mobility.jumps[direction.index()][*n as usize],
+        Move::Dove => mobility.dove,
+        Move::KnightForward => mobility.knight_forward,
+        Move::KnightBackward => mobility.knight_backward,
+        Move::Hook(direction) => mobility.hook[direction.index()],
+        Move::FullLion => mobility.full_lion,
+        Move::LimitedLion => mobility.limited_lion,
+        Move::JumpThenRange(_, direction) => mobility.jump_then_range[direction.index()],
+        Move::FlyingJump(direction) => mobility.flying_jump[direction.index()],
+        Move::FlyingCapture(direction) => mobility.flying_capture[direction.index()],
+        Move::JumpOrRange(n, direction) => {
+            mobility.jumps[direction.index()][*n as usize] + mobility.range[direction.index()]
         }
+        Move::RangeCapture(direction) => mobility.range_capture[rank.index()][direction.index()],
     }
 }
@@ -1,19 +1,691 @@
+use clap::{
+    error::ErrorKind, parser::ValueSource, CommandFactory, FromArgMatches, Parser, Subcommand,
+    ValueEnum,
+};
 use num_format::{Locale, ToFormattedString};
-use shogi_piece_values::{simulate_n_par, assign_values_to_faces};
+use rand::{thread_rng, Rng};
+use shogi_piece_values::{
+    assign_values_to_faces_with, assign_weighted_value_to_face, default_batch_size,
+    estimate_relative_values_of_faces_with, faces, load_checkpoint, load_faces, occupancy_model,
+    save_checkpoint, simulate_in_batches, simulate_n_par_seeded, to_betza, validate_faces, variant,
+    variants, Board, BoardComparison, Catalogue, Charset, Convergence, Diagram, Estimate, Face,
+    OccupancyModel, Problem, ProgressTracker, PromotionGraph, Rank, RankMix, Ranked, Simulation,
+    SimulationRecord, SimulationStatistics, ValueEstimates, Variant,
+};
+use std::io::{self, IsTerminal};
+use std::ops::ControlFlow;
+use std::path::PathBuf;
 use std::time::Instant;
 
+#[derive(Parser)]
+#[command(about = "Estimates taikyoku shogi piece values from simulated mobility")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    options: Options,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the averaged mobility statistics of the simulated positions
+    Simulate,
+    /// Print the value of every face (the default)
+    Values,
+    /// Print a single face, found by name, kanji or romaji, with its moves and value
+    #[command(alias = "show-face")]
+    Show { name: String },
+    /// Print the value of every face on each preset board, from smallest to largest
+    CompareBoards,
+    /// Write an SVG reference card for every face, or those given with --faces
+    Cards { dir: PathBuf },
+    /// Draw the movement diagrams of the given faces, or of every face
+    Diagram { names: Vec<String> },
+    /// Check the face table for unknown promotions, duplicates and unsupported moves
+    Validate,
+}
+
+#[derive(clap::Args)]
+struct Options {
+    /// Number of trials to simulate, or the trial budget with --tolerance
+    #[arg(short = 'n', long, default_value_t = 100_000, global = true)]
+    trials: usize,
+
+    /// Board to simulate: chu, dai, tenjiku, dai-dai, maka-dai-dai, tai,
+    /// taikyoku, or WIDTHxHEIGHT
+    #[arg(short, long, default_value = "taikyoku", global = true)]
+    board: Board,
+
+    /// How pieces are laid out in simulated positions: uniform (anywhere on
+    /// the board), setup[:FRACTION] (each army in its own camp, in the
+    /// starting array where the board has one, up to that fraction of it
+    /// already captured), density:FRACTION (that share of the board filled),
+    /// phase[:opening|middlegame|endgame] (armies thinned as the game goes
+    /// on), territorial (each side crowding its own edge), or replay:FILE
+    /// (positions read from a file)
+    #[arg(long, default_value = "uniform", global = true)]
+    occupancy: String,
+
+    /// Seed for reproducible runs; a random seed is used when omitted
+    #[arg(short, long, global = true)]
+    seed: Option<u64>,
+
+    /// Number of worker threads (defaults to one per core)
+    #[arg(short = 'j', long, global = true)]
+    threads: Option<usize>,
+
+    /// Keep simulating until every value's 95% confidence half-width is below this
+    #[arg(long, global = true)]
+    tolerance: Option<f64>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Text, global = true)]
+    format: Format,
+
+    /// Only print these faces (comma separated names, kanji or romaji)
+    #[arg(long, value_delimiter = ',', global = true)]
+    faces: Vec<String>,
+
+    /// Read the faces from this TOML (or .json) file instead of the built-in table
+    #[arg(long, global = true)]
+    faces_file: Option<PathBuf>,
+
+    /// Use the faces of this variant on its board: chu, dai, tenjiku or taikyoku
+    #[arg(long, global = true, value_parser = parse_variant, conflicts_with_all = ["board", "faces_file"])]
+    variant: Option<Variant>,
+
+    /// Face whose value is used as the unit of all other values
+    #[arg(short, long, default_value = "Pawn", global = true)]
+    reference: String,
+
+    /// Blend each face's value with its promoted face's value, assuming it
+    /// promotes with this probability
+    #[arg(long, global = true, value_parser = parse_probability)]
+    promotion_probability: Option<f64>,
+
+    /// Value a capture at this many times a move to an empty square
+    #[arg(long, default_value_t = 1.0, global = true, value_parser = parse_capture_weight)]
+    capture_weight: f64,
+
+    /// Draw movement diagrams with ASCII characters only
+    #[arg(long, global = true)]
+    ascii: bool,
+
+    /// Also print the simulation table when printing values
+    #[arg(long, global = true)]
+    show_simulation: bool,
+
+    /// Print values with their 95% confidence intervals instead of rounding
+    #[arg(long, global = true)]
+    intervals: bool,
+
+    /// Save the simulation results, or the merged loaded results, to this file
+    #[arg(long, global = true)]
+    save: Option<PathBuf>,
+
+    /// Reuse results saved with --save instead of simulating; results loaded
+    /// from several files are merged
+    #[arg(long, global = true, conflicts_with_all = ["tolerance", "seed", "checkpoint"])]
+    load: Vec<PathBuf>,
+
+    /// Periodically save the running results to this file
+    #[arg(long, global = true)]
+    checkpoint: Option<PathBuf>,
+
+    /// Number of trials between checkpoints
+    #[arg(long, default_value_t = 10_000, global = true)]
+    checkpoint_interval: usize,
+
+    /// Do not print a progress line while simulating
+    #[arg(long, global = true)]
+    no_progress: bool,
+
+    /// Continue the run saved in --checkpoint up to --trials trials
+    #[arg(long, global = true, requires = "checkpoint", conflicts_with = "seed")]
+    resume: bool,
+
+    // Whether --board (or --variant) and --occupancy were given rather than defaulted,
+    // so that they can be checked against loaded results.
+    #[arg(skip)]
+    board_given: bool,
+
+    #[arg(skip)]
+    occupancy_given: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
 fn main() {
-    let n = 100_000;
+    let matches = Cli::command().get_matches();
+    let Cli {
+        command,
+        mut options,
+    } = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let given = |id: &str| {
+        std::iter::successors(Some(&matches), |m| m.subcommand().map(|(_, m)| m))
+            .any(|m| m.value_source(id) == Some(ValueSource::CommandLine))
+    };
+    options.board_given = given("board") || given("variant");
+    options.occupancy_given = given("occupancy");
+
+    if let Some(threads) = options.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .unwrap();
+    }
+
+    let faces = match (&options.faces_file, &options.variant) {
+        (Some(path), _) => load_faces(path).unwrap_or_else(|e| {
+            fail(
+                ErrorKind::Io,
+                format!("could not load {}: {e}", path.display()),
+            )
+        }),
+        (None, Some(variant)) => {
+            options.board = variant.board;
+            variant.faces.clone()
+        }
+        (None, None) => faces(),
+    };
+    let catalogue = Catalogue::new(&faces);
+    if let Some(Command::Validate) = command {
+        validate(catalogue.faces());
+    }
+    let charset = if options.ascii {
+        Charset::Ascii
+    } else {
+        Charset::Unicode
+    };
+    if let Some(Command::Diagram { names }) = &command {
+        let selection: Vec<Face> = match names.is_empty() {
+            true => catalogue.faces().to_vec(),
+            false => names
+                .iter()
+                .map(|name| find_face(&catalogue, name))
+                .collect(),
+        };
+        for (i, face) in selection.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            print_diagram(face, charset);
+        }
+        return;
+    }
+    // Moves beyond the simulated distances cannot be valued at all.
+    if let Some(problem) = validate_faces(catalogue.faces())
+        .into_iter()
+        .find(|problem| {
+            matches!(
+                problem,
+                Problem::StepOutOfRange { .. } | Problem::JumpOutOfRange { .. }
+            )
+        })
+    {
+        fail(ErrorKind::InvalidValue, problem);
+    }
+
+    let ranks = RankMix::of(catalogue.faces());
+    let occupancy = Ranked {
+        model: occupancy_model(&options.occupancy, options.board)
+            .unwrap_or_else(|e| fail(ErrorKind::InvalidValue, e)),
+        ranks,
+    };
+    let reference = find_face(&catalogue, &options.reference);
+    let selection: Vec<Face> = options
+        .faces
+        .iter()
+        .map(|name| find_face(&catalogue, name))
+        .collect();
+
+    let promotions = options.promotion_probability.map(|probability| {
+        let graph = PromotionGraph::new(catalogue.faces())
+            .unwrap_or_else(|e| fail(ErrorKind::InvalidValue, e));
+        (graph, probability)
+    });
+    let weighted = |simulation: &Simulation, face: &Face| {
+        assign_weighted_value_to_face(simulation, face, options.capture_weight)
+    };
+    let value = |simulation: &Simulation, face: &Face| match &promotions {
+        Some((graph, probability)) => {
+            graph.blended_value_with(simulation, face, *probability, weighted)
+        }
+        None => weighted(simulation, face),
+    };
+
+    let selected =
+        |face: &Face| selection.is_empty() || selection.iter().any(|s| s.name == face.name);
+    let shown: Vec<Face> = catalogue
+        .faces()
+        .iter()
+        .filter(|f| selected(f))
+        .cloned()
+        .collect();
+    let valuation = Valuation {
+        faces: &shown,
+        value: &value,
+        reference: &reference,
+    };
+
+    match command.unwrap_or(Command::Values) {
+        Command::Simulate => {
+            let (simulation, _) = run(&options, &occupancy, &selection, &valuation);
+            let simulation = simulation.clone() / value(&simulation, &reference);
+            match options.format {
+                Format::Text => println!("{simulation}"),
+                Format::Json => println!("{}", simulation.to_json()),
+                Format::Csv => print!("{}", simulation.to_csv()),
+            }
+        }
+        Command::Values => {
+            let (simulation, statistics) = run(&options, &occupancy, &selection, &valuation);
+            let simulation = simulation.clone() / value(&simulation, &reference);
+
+            if options.show_simulation {
+                match options.format {
+                    Format::Text => println!("{simulation}\n"),
+                    Format::Json => println!("{}", simulation.to_json()),
+                    Format::Csv => println!("{}", simulation.to_csv()),
+                }
+            }
+
+            if options.intervals {
+                let statistics = statistics.unwrap_or_else(|| {
+                    fail(
+                        ErrorKind::ArgumentConflict,
+                        "the loaded results have no variance to compute intervals from",
+                    )
+                });
+                let mut estimates = estimate_relative_values_of_faces_with(
+                    catalogue.faces(),
+                    &statistics,
+                    |sim| value(sim, &reference),
+                    value,
+                );
+                estimates.retain(selected);
+                match options.format {
+                    Format::Text => println!("{estimates}"),
+                    Format::Json => println!("{}", estimates.to_json()),
+                    Format::Csv => print!("{}", estimates.to_csv()),
+                }
+            } else {
+                let mut value_assignments =
+                    assign_values_to_faces_with(catalogue.faces(), &simulation, value);
+                value_assignments.retain(selected);
+                match options.format {
+                    Format::Text => println!("{value_assignments}"),
+                    Format::Json => println!("{}", value_assignments.to_json()),
+                    Format::Csv => print!("{}", value_assignments.to_csv()),
+                }
+            }
+        }
+        Command::CompareBoards => {
+            if !options.load.is_empty() || options.checkpoint.is_some() {
+                fail(
+                    ErrorKind::ArgumentConflict,
+                    "boards are compared with fresh runs, which cannot be loaded or checkpointed",
+                );
+            }
+            let seed = options.seed.unwrap_or_else(|| thread_rng().gen());
+            let runs: Vec<(String, Board, Simulation)> = Board::PRESETS
+                .iter()
+                .map(|(name, board)| {
+                    let occupancy = Ranked {
+                        model: occupancy_model(&options.occupancy, *board)
+                            .unwrap_or_else(|e| fail(ErrorKind::InvalidValue, e)),
+                        ranks,
+                    };
+                    let simulation =
+                        simulate_n_par_seeded(*board, &occupancy, options.trials, seed);
+                    eprintln!(
+                        "Finished {} simulations on the {name} board ({board}).",
+                        options.trials.to_formatted_string(&Locale::en)
+                    );
+                    let simulation = simulation.clone() / value(&simulation, &reference);
+                    (name.to_string(), *board, simulation)
+                })
+                .collect();
+            let mut comparison = BoardComparison::new(catalogue.faces(), &runs, value);
+            comparison.retain(selected);
+            match options.format {
+                Format::Text => print!("{comparison}"),
+                Format::Json => println!("{}", comparison.to_json()),
+                Format::Csv => print!("{}", comparison.to_csv()),
+            }
+        }
+        Command::Cards { dir } => {
+            let (simulation, statistics) = run(&options, &occupancy, &selection, &valuation);
+            let mut estimates = match statistics {
+                Some(statistics) => estimate_relative_values_of_faces_with(
+                    catalogue.faces(),
+                    &statistics,
+                    |sim| value(sim, &reference),
+                    value,
+                ),
+                None => ValueEstimates(
+                    catalogue
+                        .faces()
+                        .iter()
+                        .map(|face| {
+                            let estimate = Estimate {
+                                mean: value(&simulation, face) / value(&simulation, &reference),
+                                standard_error: f64::NAN,
+                            };
+                            (face.clone(), estimate)
+                        })
+                        .collect(),
+                ),
+            };
+            estimates.retain(selected);
+            let written = estimates.write_cards(&dir).unwrap_or_else(|e| {
+                fail(
+                    ErrorKind::Io,
+                    format!("could not write cards to {}: {e}", dir.display()),
+                )
+            });
+            eprintln!("Wrote {} cards to {}.", written.len(), dir.display());
+        }
+        // Handled before the reference face is looked up.
+        Command::Validate | Command::Diagram { .. } => unreachable!(),
+        Command::Show { name } => {
+            let face = find_face(&catalogue, &name);
+            let valuation = Valuation {
+                faces: std::slice::from_ref(&face),
+                value: &value,
+                reference: &reference,
+            };
+            let (simulation, statistics) = run(&options, &occupancy, &selection, &valuation);
+            let estimate = match statistics {
+                Some(statistics) => {
+                    statistics.estimate_ratio(|sim| value(sim, &face), |sim| value(sim, &reference))
+                }
+                None => Estimate {
+                    mean: value(&simulation, &face) / value(&simulation, &reference),
+                    standard_error: f64::NAN,
+                },
+            };
+
+            match options.format {
+                Format::Text => {
+                    println!(
+                        "{} ({}{}, {})",
+                        face.name,
+                        face.additional_kanji.as_deref().unwrap_or_default(),
+                        face.kanji,
+                        face.romaji
+                    );
+                    if let Some(promotion) = &face.promotion {
+                        println!("Promotes to: {promotion}");
+                    }
+                    if face.rank != Rank::Ordinary {
+                        println!("Rank: {:?}", face.rank);
+                    }
+                    println!("Moves:");
+                    for m in &face.moves {
+                        println!("\t{m:?}");
+                    }
+                    print!("{}", indent(&Diagram::new(&face.moves).render(charset)));
+                    println!("\t{}", Diagram::new(&face.moves).legend(charset));
+                    println!("Betza: {}", to_betza(&face.moves));
+                    if estimate.standard_error.is_nan() {
+                        println!("Value: {:.2}", estimate.mean);
+                    } else {
+                        println!("Value: {estimate}");
+                    }
+                }
+                Format::Json => println!("{}", ValueEstimates(vec![(face, estimate)]).to_json()),
+                Format::Csv => print!("{}", ValueEstimates(vec![(face, estimate)]).to_csv()),
+            }
+        }
+    }
+}
+
+// The faces a run reports on and converges for, valued as they are printed.
+struct Valuation<'a> {
+    faces: &'a [Face],
+    value: &'a dyn Fn(&Simulation, &Face) -> f64,
+    reference: &'a Face,
+}
+
+impl Valuation<'_> {
+    fn reference(&self, simulation: &Simulation) -> f64 {
+        (self.value)(simulation, self.reference)
+    }
+
+    fn assess(&self, statistics: &SimulationStatistics, tolerance: f64) -> Convergence {
+        Convergence::assess(
+            statistics.clone(),
+            tolerance,
+            self.faces,
+            self.value,
+            |sim| self.reference(sim),
+        )
+    }
+}
+
+// Statistics are only available for fresh runs and for records saved with them.
+fn run(
+    options: &Options,
+    occupancy: &dyn OccupancyModel,
+    watched: &[Face],
+    valuation: &Valuation,
+) -> (Simulation, Option<SimulationStatistics>) {
+    if !options.load.is_empty() {
+        let record = options
+            .load
+            .iter()
+            .map(|path| {
+                SimulationRecord::load(path).unwrap_or_else(|e| {
+                    fail(
+                        ErrorKind::Io,
+                        format!("could not load {}: {e}", path.display()),
+                    )
+                })
+            })
+            .reduce(|merged, record| {
+                merged.merge(record).unwrap_or_else(|e| {
+                    fail(
+                        ErrorKind::InvalidValue,
+                        format!("could not merge results: {e}"),
+                    )
+                })
+            })
+            .unwrap();
+        if options.board_given && record.board().ok() != Some(options.board) {
+            fail(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "the loaded results were simulated on a {}x{} board, not {}",
+                    record.board_width, record.board_height, options.board
+                ),
+            );
+        }
+        if options.occupancy_given && record.occupancy_model != occupancy.to_string() {
+            fail(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "the loaded results were simulated with the {} occupancy model, not {occupancy}",
+                    record.occupancy_model
+                ),
+            );
+        }
+        eprintln!(
+            "Loaded {} simulations from {} file(s).",
+            record.trials.to_formatted_string(&Locale::en),
+            options.load.len()
+        );
+        save(options, &record);
+        return (record.simulation, record.statistics);
+    }
+
+    let (statistics, seed) = if options.resume {
+        let path = options.checkpoint.as_ref().unwrap();
+        let (statistics, seed) =
+            load_checkpoint(options.board, occupancy, path).unwrap_or_else(|e| {
+                fail(
+                    ErrorKind::Io,
+                    format!("could not resume {}: {e}", path.display()),
+                )
+            });
+        eprintln!(
+            "Resuming from {} simulations with seed {}.",
+            statistics.count().to_formatted_string(&Locale::en),
+            seed
+        );
+        (statistics, seed)
+    } else {
+        let seed = options.seed.unwrap_or_else(|| thread_rng().gen());
+        (SimulationStatistics::new(), seed)
+    };
     let timer = Instant::now();
-    let simulation = simulate_n_par(n);
-    let pawn = simulation.pawn();
-    let simulation = simulation / pawn;
-    let value_assignments = assign_values_to_faces(&simulation);
-    println!(
-        "Finished {} simulations in ~{} seconds.",
-        n.to_formatted_string(&Locale::en),
+
+    let show_progress = !options.no_progress && io::stderr().is_terminal();
+    let tracker = ProgressTracker::new(&statistics, options.trials);
+    let batch_size = match &options.checkpoint {
+        Some(_) => options.checkpoint_interval,
+        None if show_progress || options.tolerance.is_some() => default_batch_size(),
+        None => options.trials,
+    };
+    let statistics = simulate_in_batches(
+        statistics,
+        options.board,
+        occupancy,
+        options.trials,
+        seed,
+        batch_size,
+        |statistics| {
+            if show_progress {
+                let progress = tracker.update(statistics);
+                let mut line = progress.to_string();
+                for face in watched {
+                    let estimate = progress
+                        .estimate_with(face, valuation.value, |sim| valuation.reference(sim));
+                    line += &format!(", {}: {estimate}", face.name);
+                }
+                eprint!("\r\x1b[K{line}");
+            }
+            if let Some(path) = &options.checkpoint {
+                save_checkpoint(options.board, occupancy, statistics, seed, path).unwrap_or_else(
+                    |e| {
+                        fail(
+                            ErrorKind::Io,
+                            format!("could not save {}: {e}", path.display()),
+                        )
+                    },
+                );
+            }
+            match options.tolerance {
+                Some(tolerance) if valuation.assess(statistics, tolerance).converged => {
+                    ControlFlow::Break(())
+                }
+                _ => ControlFlow::Continue(()),
+            }
+        },
+    );
+    if show_progress {
+        eprintln!();
+    }
+    if let Some(tolerance) = options.tolerance {
+        eprintln!("{}.", valuation.assess(&statistics, tolerance));
+    }
+
+    eprintln!(
+        "Finished {} simulations with seed {} in ~{} seconds.",
+        statistics.count().to_formatted_string(&Locale::en),
+        seed,
         timer.elapsed().as_secs()
     );
-    println!("{simulation}\n");
-    println!("{value_assignments}");
+
+    save(
+        options,
+        &SimulationRecord::from_statistics(
+            options.board,
+            occupancy,
+            statistics.clone(),
+            Some(seed),
+        ),
+    );
+    (statistics.mean(), Some(statistics))
+}
+
+fn save(options: &Options, record: &SimulationRecord) {
+    if let Some(path) = &options.save {
+        record.save(path).unwrap_or_else(|e| {
+            fail(
+                ErrorKind::Io,
+                format!("could not save {}: {e}", path.display()),
+            )
+        });
+    }
+}
+
+fn print_diagram(face: &Face, charset: Charset) {
+    let diagram = Diagram::new(&face.moves);
+    println!("{} ({})", face.name, face.kanji);
+    print!("{}", indent(&diagram.render(charset)));
+    println!("\t{}", diagram.legend(charset));
+}
+
+fn indent(text: &str) -> String {
+    text.lines().map(|line| format!("\t{line}\n")).collect()
+}
+
+fn validate(catalogue: &[Face]) -> ! {
+    let problems = validate_faces(catalogue);
+    for problem in &problems {
+        println!("{problem}");
+    }
+    if problems.is_empty() {
+        println!("No problems found in {} faces.", catalogue.len());
+        std::process::exit(0);
+    }
+    eprintln!(
+        "Found {} problem(s) in {} faces.",
+        problems.len(),
+        catalogue.len()
+    );
+    std::process::exit(1);
+}
+
+fn parse_variant(s: &str) -> Result<Variant, String> {
+    variant(s).ok_or_else(|| {
+        let names: Vec<String> = variants().into_iter().map(|v| v.name).collect();
+        format!("expected one of {}", names.join(", "))
+    })
+}
+
+fn parse_probability(s: &str) -> Result<f64, String> {
+    match s.parse() {
+        Ok(p) if (0.0..=1.0).contains(&p) => Ok(p),
+        Ok(_) => Err("must be between 0 and 1".to_string()),
+        Err(e) => Err(format!("{e}")),
+    }
+}
+
+fn parse_capture_weight(s: &str) -> Result<f64, String> {
+    match s.parse() {
+        Ok(w) if f64::is_finite(w) && w >= 0.0 => Ok(w),
+        Ok(_) => Err("must be a non-negative number".to_string()),
+        Err(e) => Err(format!("{e}")),
+    }
+}
+
+// Faces that merely share a name (the table has two Running Boars) resolve to
+// the first of them; anything else matching several faces is ambiguous.
+fn find_face(catalogue: &Catalogue, name: &str) -> Face {
+    catalogue
+        .find(name)
+        .unwrap_or_else(|e| fail(ErrorKind::InvalidValue, e))
+        .clone()
+}
+
+fn fail(kind: ErrorKind, message: impl std::fmt::Display) -> ! {
+    Cli::command().error(kind, message).exit()
 }
@@ -0,0 +1,130 @@
+use num_format::{Locale, ToFormattedString};
+use std::fmt::Display;
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
+
+use crate::assign_value_to_face;
+use crate::face::Face;
+use crate::simulate::{
+    simulate_in_batches, Board, Estimate, OccupancyModel, Simulation, SimulationStatistics,
+};
+
+// Remembers when (and from how many trials) a run started, so that rates and
+// ETAs only count the trials simulated in this session, even when resuming.
+pub struct ProgressTracker {
+    started: Instant,
+    initial_trials: u64,
+    total_trials: u64,
+}
+
+impl ProgressTracker {
+    pub fn new(statistics: &SimulationStatistics, total_trials: usize) -> Self {
+        ProgressTracker {
+            started: Instant::now(),
+            initial_trials: statistics.count(),
+            total_trials: total_trials as u64,
+        }
+    }
+
+    pub fn update<'a>(&self, statistics: &'a SimulationStatistics) -> Progress<'a> {
+        let elapsed = self.started.elapsed();
+        let simulated = statistics.count().saturating_sub(self.initial_trials);
+        let trials_per_second = simulated as f64 / elapsed.as_secs_f64();
+        let remaining = self.total_trials.saturating_sub(statistics.count());
+        let eta = (trials_per_second > 0.0)
+            .then(|| Duration::from_secs_f64(remaining as f64 / trials_per_second));
+
+        Progress {
+            trials_completed: statistics.count(),
+            total_trials: self.total_trials,
+            elapsed,
+            trials_per_second,
+            eta,
+            statistics,
+        }
+    }
+}
+
+pub struct Progress<'a> {
+    pub trials_completed: u64,
+    pub total_trials: u64,
+    pub elapsed: Duration,
+    pub trials_per_second: f64,
+    pub eta: Option<Duration>,
+    pub statistics: &'a SimulationStatistics,
+}
+
+impl Progress<'_> {
+    pub fn fraction(&self) -> f64 {
+        self.trials_completed as f64 / self.total_trials as f64
+    }
+
+    // The pawn-relative value of `face` from the trials simulated so far.
+    pub fn estimate(&self, face: &Face) -> Estimate {
+        self.estimate_with(face, assign_value_to_face, Simulation::pawn)
+    }
+
+    pub fn estimate_with(
+        &self,
+        face: &Face,
+        value: impl Fn(&Simulation, &Face) -> f64,
+        reference: impl Fn(&Simulation) -> f64,
+    ) -> Estimate {
+        self.statistics
+            .estimate_ratio(|sim| value(sim, face), reference)
+    }
+}
+
+impl Display for Progress<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{} trials ({:.1}%), {:.0} trials/s",
+            self.trials_completed.to_formatted_string(&Locale::en),
+            self.total_trials.to_formatted_string(&Locale::en),
+            100.0 * self.fraction(),
+            self.trials_per_second
+        )?;
+        if let Some(eta) = self.eta {
+            write!(f, ", ETA {}", format_duration(eta))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m {seconds:02}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+pub fn simulate_with_progress(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    trials: usize,
+    seed: u64,
+    batch_size: usize,
+    mut on_progress: impl FnMut(&Progress),
+) -> SimulationStatistics {
+    let statistics = SimulationStatistics::new();
+    let tracker = ProgressTracker::new(&statistics, trials);
+    simulate_in_batches(
+        statistics,
+        board,
+        occupancy,
+        trials,
+        seed,
+        batch_size,
+        |statistics| {
+            on_progress(&tracker.update(statistics));
+            ControlFlow::Continue(())
+        },
+    )
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::assign_value_to_face;
+use crate::face::Face;
+use crate::simulate::Simulation;
+
+// Which face each face turns into when it promotes, with the names in
+// `Face::promotion` resolved. Faces are looked up by name; where a name is
+// used twice, the first face with it is the one promoted to.
+pub struct PromotionGraph {
+    faces: Vec<Face>,
+    index: HashMap<String, usize>,
+    promotions: Vec<Option<usize>>,
+}
+
+impl PromotionGraph {
+    pub fn new(faces: &[Face]) -> Result<PromotionGraph, PromotionError> {
+        let mut index = HashMap::new();
+        for (i, face) in faces.iter().enumerate() {
+            index.entry(face.name.clone()).or_insert(i);
+        }
+
+        let promotions = faces
+            .iter()
+            .map(|face| match &face.promotion {
+                Some(name) => match index.get(name) {
+                    Some(&i) => Ok(Some(i)),
+                    None => Err(PromotionError {
+                        face: face.name.clone(),
+                        promotion: name.clone(),
+                    }),
+                },
+                None => Ok(None),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(PromotionGraph {
+            faces: faces.to_vec(),
+            index,
+            promotions,
+        })
+    }
+
+    pub fn faces(&self) -> &[Face] {
+        &self.faces
+    }
+
+    pub fn promotion(&self, face: &Face) -> Option<&Face> {
+        let i = *self.index.get(&face.name)?;
+        self.promotions[i].map(|p| &self.faces[p])
+    }
+
+    pub fn promoted_from(&self, face: &Face) -> Vec<&Face> {
+        let Some(&target) = self.index.get(&face.name) else {
+            return Vec::new();
+        };
+        self.promotions
+            .iter()
+            .zip(&self.faces)
+            .filter(|(promotion, _)| **promotion == Some(target))
+            .map(|(_, face)| face)
+            .collect()
+    }
+
+    // The face's value if it promotes with the given probability, counting
+    // the promoted face at its plain mobility value: a promoted piece cannot
+    // promote again.
+    pub fn blended_value(&self, simulation: &Simulation, face: &Face, probability: f64) -> f64 {
+        self.blended_value_with(simulation, face, probability, assign_value_to_face)
+    }
+
+    // As `blended_value`, valuing both faces with `value`.
+    pub fn blended_value_with(
+        &self,
+        simulation: &Simulation,
+        face: &Face,
+        probability: f64,
+        value: impl Fn(&Simulation, &Face) -> f64,
+    ) -> f64 {
+        let own = value(simulation, face);
+        match self.promotion(face) {
+            Some(promoted) => (1.0 - probability) * own + probability * value(simulation, promoted),
+            None => own,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PromotionError {
+    pub face: String,
+    pub promotion: String,
+}
+
+impl Display for PromotionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} promotes to unknown face '{}'",
+            self.face, self.promotion
+        )
+    }
+}
+
+impl std::error::Error for PromotionError {}
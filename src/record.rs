@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+use crate::export::FormatError;
+use crate::simulate::{Board, BoardError, OccupancyModel, Simulation, SimulationStatistics};
+
+// The averaged result of a simulation run together with everything needed to
+// tell whether two runs are comparable. Saved as JSON so that face values can
+// be reassigned without simulating again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationRecord {
+    pub trials: u64,
+    pub seed: Option<u64>,
+    pub board_width: usize,
+    pub board_height: usize,
+    pub occupancy_model: String,
+    pub simulation: Simulation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statistics: Option<SimulationStatistics>,
+}
+
+impl SimulationRecord {
+    pub fn new(
+        board: Board,
+        occupancy: &dyn OccupancyModel,
+        simulation: Simulation,
+        trials: u64,
+        seed: Option<u64>,
+    ) -> Self {
+        SimulationRecord {
+            trials,
+            seed,
+            board_width: board.width(),
+            board_height: board.height(),
+            occupancy_model: occupancy.to_string(),
+            simulation,
+            statistics: None,
+        }
+    }
+
+    pub fn from_statistics(
+        board: Board,
+        occupancy: &dyn OccupancyModel,
+        statistics: SimulationStatistics,
+        seed: Option<u64>,
+    ) -> Self {
+        SimulationRecord {
+            statistics: Some(statistics.clone()),
+            ..SimulationRecord::new(
+                board,
+                occupancy,
+                statistics.mean(),
+                statistics.count(),
+                seed,
+            )
+        }
+    }
+
+    pub fn board(&self) -> Result<Board, BoardError> {
+        Board::new(self.board_width, self.board_height)
+    }
+
+    // Writes to a temporary file first so that an interrupted save never
+    // leaves a truncated record (or checkpoint) behind.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), FormatError> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self)?;
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, json)?;
+        fs::rename(&temporary, path)?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FormatError> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    // Combines two runs as if their trials had been simulated together. The
+    // variance survives only if both records carry their statistics; otherwise
+    // the means are weighted by trial count.
+    pub fn merge(self, other: SimulationRecord) -> Result<SimulationRecord, MergeError> {
+        if (self.board_width, self.board_height) != (other.board_width, other.board_height) {
+            return Err(MergeError::BoardMismatch);
+        }
+        if self.occupancy_model != other.occupancy_model {
+            return Err(MergeError::OccupancyModelMismatch);
+        }
+        if let (Some(a), Some(b)) = (self.seed, other.seed) {
+            if a == b {
+                return Err(MergeError::SameSeed(a));
+            }
+        }
+
+        let trials = self.trials + other.trials;
+        let (simulation, statistics) = match (self.statistics, other.statistics) {
+            (Some(a), Some(b)) => {
+                let statistics = a + b;
+                (statistics.mean(), Some(statistics))
+            }
+            _ => {
+                let (wa, wb) = (self.trials as f64, other.trials as f64);
+                let fields: Vec<f64> = self
+                    .simulation
+                    .to_fields()
+                    .iter()
+                    .zip(other.simulation.to_fields())
+                    .map(|(a, b)| (a * wa + b * wb) / (wa + wb))
+                    .collect();
+                (Simulation::from_fields(&fields), None)
+            }
+        };
+
+        Ok(SimulationRecord {
+            trials,
+            seed: None,
+            simulation,
+            statistics,
+            ..self
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum MergeError {
+    BoardMismatch,
+    OccupancyModelMismatch,
+    SameSeed(u64),
+}
+
+impl Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeError::BoardMismatch => write!(f, "the runs used different board sizes"),
+            MergeError::OccupancyModelMismatch => {
+                write!(f, "the runs used different occupancy models")
+            }
+            MergeError::SameSeed(seed) => write!(
+                f,
+                "both runs used seed {seed}, so they simulated the same positions"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
@@ -1,1005 +1,527 @@
+mod board;
 mod grid;
+mod occupancy;
 mod simulation;
+mod statistics;
+
+pub use board::{Board, BoardError, MAX_BOARD_SIZE};
+pub use grid::{Grid, Square};
+pub use occupancy::{
+    occupancy_model, FixedDensity, GamePhase, OccupancyError, OccupancyModel, RankMix, Ranked,
+    Replay, Setup, Territorial, Uniform,
+};
+pub use simulation::{Mobility, Simulation};
+pub use statistics::{Estimate, SimulationStatistics};
+
+use std::ops::{Add, AddAssign, ControlFlow, Range};
+
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::face::Rank;
+use grid::Ray;
+
+// Trials are accumulated in fixed-size chunks, and the chunks are then merged
+// in order, so the result never depends on how rayon splits the work. Runs that
+// are extended in batches of whole chunks give the same result as a single run.
+pub const CHUNK_SIZE: usize = 256;
+
+pub fn simulate_n(board: Board, occupancy: &dyn OccupancyModel, n: usize) -> Simulation {
+    simulate_n_seeded(board, occupancy, n, thread_rng().gen())
+}
+
+pub fn simulate_n_par(board: Board, occupancy: &dyn OccupancyModel, n: usize) -> Simulation {
+    simulate_n_par_seeded(board, occupancy, n, thread_rng().gen())
+}
+
+pub fn simulate_n_seeded(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    n: usize,
+    seed: u64,
+) -> Simulation {
+    simulate_n_statistics(board, occupancy, n, seed).mean()
+}
+
+pub fn simulate_n_par_seeded(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    n: usize,
+    seed: u64,
+) -> Simulation {
+    simulate_n_par_statistics(board, occupancy, n, seed).mean()
+}
+
+pub fn simulate_n_statistics(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    n: usize,
+    seed: u64,
+) -> SimulationStatistics {
+    chunks(0..n)
+        .map(|trials| simulate_chunk(board, occupancy, seed, trials))
+        .fold(SimulationStatistics::new(), SimulationStatistics::add)
+}
+
+pub fn simulate_n_par_statistics(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    n: usize,
+    seed: u64,
+) -> SimulationStatistics {
+    extend_statistics_par(SimulationStatistics::new(), board, occupancy, 0..n, seed)
+}
+
+pub fn extend_statistics_par(
+    statistics: SimulationStatistics,
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    trials: Range<usize>,
+    seed: u64,
+) -> SimulationStatistics {
+    // Every chunk's statistics hold a full co-moment matrix, so only a few per
+    // thread are kept at a time before being merged, in order, into the total.
+    let chunks: Vec<Range<usize>> = chunks(trials).collect();
+    let window = 4 * rayon::current_num_threads();
+    chunks
+        .chunks(window)
+        .fold(statistics, |statistics, window| {
+            let window: Vec<SimulationStatistics> = window
+                .par_iter()
+                .map(|trials| simulate_chunk(board, occupancy, seed, trials.clone()))
+                .collect();
+            window
+                .into_iter()
+                .fold(statistics, SimulationStatistics::add)
+        })
+}
+
+// Large enough to keep every rayon thread busy for a few chunks per batch.
+pub fn default_batch_size() -> usize {
+    4 * CHUNK_SIZE * rayon::current_num_threads()
+}
+
+// Extends `statistics` up to `trials` trials, calling `after_batch` after every
+// `batch_size` trials so the caller can report on, save or stop the run.
+pub fn simulate_in_batches(
+    mut statistics: SimulationStatistics,
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    trials: usize,
+    seed: u64,
+    batch_size: usize,
+    mut after_batch: impl FnMut(&SimulationStatistics) -> ControlFlow<()>,
+) -> SimulationStatistics {
+    let batch_size = batch_size.max(1).div_ceil(CHUNK_SIZE) * CHUNK_SIZE;
+
+    loop {
+        let done = statistics.count() as usize;
+        if done >= trials {
+            return statistics;
+        }
 
-pub use simulation::Simulation;
-
-use std::ops::Add;
-
-use rand::{thread_rng, Rng};
-use rayon::{iter::repeatn, prelude::*};
-
-use grid::{Grid, Square, BOARD_HEIGHT, BOARD_WIDTH};
-
-pub fn simulate_n(n: usize) -> Simulation {
-    let mut sim = Simulation::new();
-    for _ in 0..n {
-        sim += simulate();
+        let end = usize::min((done / batch_size + 1) * batch_size, trials);
+        statistics = extend_statistics_par(statistics, board, occupancy, done..end, seed);
+        if after_batch(&statistics).is_break() {
+            return statistics;
+        }
     }
-    sim / n as f64
 }
 
-pub fn simulate_n_par(n: usize) -> Simulation {
-    let sim = repeatn((), n)
-        .map(|()| simulate())
-        .reduce(Simulation::new, Simulation::add);
-    sim / n as f64
+// Chunks end on multiples of CHUNK_SIZE whatever the first trial, so that a run
+// extended from any point is merged from the same chunks as a fresh one after
+// its first.
+fn chunks(trials: Range<usize>) -> impl Iterator<Item = Range<usize>> {
+    let end = trials.end;
+    std::iter::successors(Some(trials.start), |&start| {
+        Some((start / CHUNK_SIZE + 1) * CHUNK_SIZE)
+    })
+    .take_while(move |&start| start < end)
+    .map(move |start| start..usize::min((start / CHUNK_SIZE + 1) * CHUNK_SIZE, end))
 }
 
-pub fn simulate() -> Simulation {
-    let mut rng = thread_rng();
+fn simulate_chunk(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    seed: u64,
+    trials: Range<usize>,
+) -> SimulationStatistics {
+    let mut stats = SimulationStatistics::new();
+    for trial in trials {
+        stats.push(&simulate_with_rng(
+            board,
+            occupancy,
+            &mut trial_rng(seed, trial),
+        ));
+    }
+    stats
+}
 
-    let num_pieces = rng.gen_range(3..804);
-    let num_friendlies = rng.gen_range(1..i32::min(num_pieces - 1, 402));
-    let num_pieces = num_friendlies + i32::min(402, num_pieces - num_friendlies);
-    let mut grid = Grid::new();
+// Every trial draws from its own ChaCha stream, so trial `i` of a run with a
+// given seed sees the same position no matter which thread computes it.
+pub fn trial_rng(seed: u64, trial: usize) -> ChaCha8Rng {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(trial as u64);
+    rng
+}
 
-    for i in 0..num_pieces {
-        let square = if i < num_friendlies {
-            Square::Friendly
-        } else {
-            Square::Opponent
-        };
+pub fn simulate(board: Board, occupancy: &dyn OccupancyModel) -> Simulation {
+    simulate_with_rng(board, occupancy, &mut thread_rng())
+}
 
-        grid.randomly_place(&mut rng, square);
-    }
+pub fn simulate_with_rng(
+    board: Board,
+    occupancy: &dyn OccupancyModel,
+    rng: &mut impl Rng,
+) -> Simulation {
+    let mut grid = Grid::new(board);
+    occupancy.populate(&mut grid, rng);
 
-    let mut orthogonal_steps = [0.0; 8];
-    let mut diagonal_steps = [0.0; 8];
-    let mut orthogonal_jumps = [0.0; 4];
-    let mut diagonal_jumps = [0.0; 4];
-    let mut dove = 0.0;
-    let mut orthogonal_range = 0.0;
-    let mut diagonal_range = 0.0;
-    let mut knight_jump = 0.0;
-    let mut orthogonal_flying_jump = 0.0;
-    let mut diagonal_flying_jump = 0.0;
-    let mut orthogonal_flying_capture = 0.0;
-    let mut diagonal_flying_capture = 0.0;
-    let mut orthogonal_jump_then_range = 0.0;
-    let mut diagonal_jump_then_range = 0.0;
-    let mut orthogonal_hook = 0.0;
-    let mut diagonal_hook = 0.0;
-    let mut full_lion = 0.0;
-    let mut limited_lion = 0.0;
-
-    for x in 0..BOARD_WIDTH {
-        for y in 0..BOARD_HEIGHT {
+    let mut sim = Simulation::new();
+    let rays = RayTable::new(&grid);
+    for x in 0..board.width() {
+        for y in 0..board.height() {
             let x = x as i64;
             let y = y as i64;
-            for n in 0..8 {
-                orthogonal_steps[n] += step_n_orthogonal(&grid, x, y, n as i64) as f64 / 4.0;
-                diagonal_steps[n] += step_n_diagonal(&grid, x, y, n as i64) as f64 / 4.0;
-            }
-            for n in 0..4 {
-                orthogonal_jumps[n] += jump_n_orthogonal(&grid, x, y, n as i64) as f64 / 4.0;
-                diagonal_jumps[n] += jump_n_diagonal(&grid, x, y, n as i64) as f64 / 4.0;
+            for (d, &direction) in DIRECTIONS.iter().enumerate() {
+                let (dx, dy) = direction;
+                let ray = rays.get(x, y, direction);
+                for n in 0..8 {
+                    let count = slide(ray.truncate(n as u32), 0);
+                    tally(&mut sim, |m| &mut m.steps[d][n], count);
+                }
+                for n in 0..4 {
+                    let count = jump(&grid, x, y, n as i64 * dx, n as i64 * dy);
+                    tally(&mut sim, |m| &mut m.jumps[d][n], count);
+                }
+                tally(&mut sim, |m| &mut m.range[d], slide(ray, 0));
+                tally(&mut sim, |m| &mut m.flying_jump[d], flying_jump(ray));
+                tally(&mut sim, |m| &mut m.flying_capture[d], flying_capture(ray));
+                let count = jump_then_range(&rays, x, y, direction);
+                tally(&mut sim, |m| &mut m.jump_then_range[d], count);
+                tally(&mut sim, |m| &mut m.hook[d], hook(&rays, x, y, direction));
+                for rank in Rank::ALL {
+                    let (xu, yu) = (x as usize, y as usize);
+                    let senior = grid.ray_at_rank(xu, yu, direction, ray.len, rank);
+                    let count = range_capture(ray, senior);
+                    tally(&mut sim, |m| &mut m.range_capture[rank.index()][d], count);
+                }
             }
-            dove += dove_moves(&grid, x, y) as f64 / 4.0;
-            orthogonal_range += range_orthogonal(&grid, x, y) as f64 / 4.0;
-            diagonal_range += range_diagonal(&grid, x, y) as f64 / 4.0;
-            knight_jump += jump_knight(&grid, x, y) as f64 / 2.0;
-            orthogonal_flying_jump += flying_jump_orthogonal(&grid, x, y) as f64 / 4.0;
-            diagonal_flying_jump += flying_jump_diagonal(&grid, x, y) as f64 / 4.0;
-            orthogonal_flying_capture += flying_capture_orthogonal(&grid, x, y) as f64 / 4.0;
-            diagonal_flying_capture += flying_capture_diagonal(&grid, x, y) as f64 / 4.0;
-            orthogonal_jump_then_range += jump_then_range_orthogonal(&grid, x, y) as f64 / 4.0;
-            diagonal_jump_then_range += jump_then_range_diagonal(&grid, x, y) as f64 / 4.0;
-            orthogonal_hook += hook_orthogonal(&grid, x, y) as f64 / 4.0;
-            diagonal_hook += hook_diagonal(&grid, x, y) as f64 / 4.0;
-            full_lion += calc_full_lion(&grid, x, y) as f64;
-            limited_lion += calc_limited_lion(&grid, x, y) as f64;
+            let count = jump(&grid, x, y, 1, -2) + jump(&grid, x, y, -1, -2);
+            tally(&mut sim, |m| &mut m.knight_forward, count);
+            let count = jump(&grid, x, y, 1, 2) + jump(&grid, x, y, -1, 2);
+            tally(&mut sim, |m| &mut m.knight_backward, count);
+            tally(&mut sim, |m| &mut m.dove, dove_moves(&rays, x, y));
+            tally(&mut sim, |m| &mut m.full_lion, calc_full_lion(&grid, x, y));
+            tally(
+                &mut sim,
+                |m| &mut m.limited_lion,
+                calc_limited_lion(&grid, x, y),
+            );
         }
     }
-
-    Simulation {
-        orthogonal_steps,
-        diagonal_steps,
-        orthogonal_jumps,
-        diagonal_jumps,
-        dove,
-        orthogonal_range,
-        diagonal_range,
-        knight_jump,
-        orthogonal_flying_jump,
-        diagonal_flying_jump,
-        orthogonal_flying_capture,
-        diagonal_flying_capture,
-        orthogonal_jump_then_range,
-        diagonal_jump_then_range,
-        orthogonal_hook,
-        diagonal_hook,
-        full_lion,
-        limited_lion,
-    }
-}
-
-fn step_n_orthogonal(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    step_n_north(grid, x, y, n)
-        + step_n_east(grid, x, y, n)
-        + step_n_south(grid, x, y, n)
-        + step_n_west(grid, x, y, n)
+    // The dove has always been valued as the average of its four legs.
+    sim.quiet.dove /= 4.0;
+    sim.captures.dove /= 4.0;
+    sim
 }
 
-fn step_n_diagonal(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    step_n_northeast(grid, x, y, n)
-        + step_n_southeast(grid, x, y, n)
-        + step_n_southwest(grid, x, y, n)
-        + step_n_northwest(grid, x, y, n)
+// The moves from one square, split into those to an empty square and
+// captures.
+#[derive(Clone, Copy, Default)]
+struct Count {
+    quiet: i64,
+    captures: i64,
 }
 
-fn step_n_north(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 0, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + step_n_north(grid, xp, yp, n - 1),
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
+impl Count {
+    const QUIET: Count = Count {
+        quiet: 1,
+        captures: 0,
+    };
+    const CAPTURE: Count = Count {
+        quiet: 0,
+        captures: 1,
+    };
+    const NONE: Count = Count {
+        quiet: 0,
+        captures: 0,
+    };
 
-fn step_n_northeast(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + step_n_northeast(grid, xp, yp, n - 1),
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
+    fn total(&self) -> i64 {
+        self.quiet + self.captures
     }
 }
 
-fn step_n_east(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + step_n_east(grid, xp, yp, n - 1),
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
+impl Add for Count {
+    type Output = Count;
 
-fn step_n_southeast(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, 1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + step_n_southeast(grid, xp, yp, n - 1),
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
+    fn add(self, rhs: Count) -> Count {
+        Count {
+            quiet: self.quiet + rhs.quiet,
+            captures: self.captures + rhs.captures,
+        }
     }
 }
 
-fn step_n_south(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 0, y, 1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + step_n_south(grid, xp, yp, n - 1),
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
+impl AddAssign for Count {
+    fn add_assign(&mut self, rhs: Count) {
+        *self = *self + rhs;
     }
 }
 
-fn step_n_southwest(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, -1, y, 1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + step_n_southwest(grid, xp, yp, n - 1),
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
+// Adds `count` to the same field of the quiet and capture tallies.
+fn tally(sim: &mut Simulation, field: impl Fn(&mut Mobility) -> &mut f64, count: Count) {
+    *field(&mut sim.quiet) += count.quiet as f64;
+    *field(&mut sim.captures) += count.captures as f64;
 }
 
-fn step_n_west(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, -1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + step_n_west(grid, xp, yp, n - 1),
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
+const NORTH: (i64, i64) = (0, -1);
+const NORTHEAST: (i64, i64) = (1, -1);
+const EAST: (i64, i64) = (1, 0);
+const SOUTHEAST: (i64, i64) = (1, 1);
+const SOUTH: (i64, i64) = (0, 1);
+const SOUTHWEST: (i64, i64) = (-1, 1);
+const WEST: (i64, i64) = (-1, 0);
+const NORTHWEST: (i64, i64) = (-1, -1);
 
-fn step_n_northwest(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, -1, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + step_n_northwest(grid, xp, yp, n - 1),
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
+const DIRECTIONS: [(i64, i64); 8] = [
+    NORTH, NORTHEAST, EAST, SOUTHEAST, SOUTH, SOUTHWEST, WEST, NORTHWEST,
+];
 
-fn jump_n_orthogonal(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    jump_n_north(grid, x, y, n)
-        + jump_n_east(grid, x, y, n)
-        + jump_n_south(grid, x, y, n)
-        + jump_n_west(grid, x, y, n)
-}
-
-fn jump_n_diagonal(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    jump_n_northeast(grid, x, y, n)
-        + jump_n_southeast(grid, x, y, n)
-        + jump_n_southwest(grid, x, y, n)
-        + jump_n_northwest(grid, x, y, n)
-}
-
-fn try_add(x: i64, dx: i64, y: i64, dy: i64) -> Option<(i64, i64)> {
-    let x = x as i64;
-    let dx = dx as i64;
-    let y = y as i64;
-    let dy = dy as i64;
+fn try_add(board: Board, x: i64, dx: i64, y: i64, dy: i64) -> Option<(i64, i64)> {
     if x + dx > 0
         && y + dy > 0
-        && x + dx < (BOARD_WIDTH as i64) - 1
-        && y + dy < (BOARD_HEIGHT as i64) - 1
+        && x + dx < (board.width() as i64) - 1
+        && y + dy < (board.height() as i64) - 1
     {
-        Some(((x + dx) as i64, (y + dy) as i64))
+        Some((x + dx, y + dy))
     } else {
         None
     }
 }
 
-fn jump_n_north(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, 0, y, -n) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        }
-    } else {
-        0
+fn direction_index((dx, dy): (i64, i64)) -> usize {
+    match (dx, dy) {
+        (0, -1) => 0,
+        (1, -1) => 1,
+        (1, 0) => 2,
+        (1, 1) => 3,
+        (0, 1) => 4,
+        (-1, 1) => 5,
+        (-1, 0) => 6,
+        _ => 7,
     }
 }
 
-fn jump_n_northeast(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, n, y, -n) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
+// How many squares a piece at (x, y) can pass along `direction` before
+// `try_add` would leave the inner board.
+fn reach(board: Board, x: i64, y: i64, (dx, dy): (i64, i64)) -> u32 {
+    fn axis(p: i64, d: i64, size: usize) -> i64 {
+        let last = size as i64 - 2;
+        match d {
+            0 if (1..=last).contains(&p) => i64::MAX,
+            0 => 0,
+            1 => last - p,
+            _ => p - 1,
         }
-    } else {
-        0
     }
-}
 
-fn jump_n_east(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, n, y, 0) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        }
-    } else {
-        0
-    }
+    axis(x, dx, board.width())
+        .min(axis(y, dy, board.height()))
+        .max(0) as u32
 }
 
-fn jump_n_southeast(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, n, y, n) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        }
-    } else {
-        0
-    }
+// The full ray from every square in every direction, built once per trial so
+// that each move type only has to shorten or look up rays.
+struct RayTable {
+    board: Board,
+    rays: Vec<[Ray; 8]>,
 }
 
-fn jump_n_south(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, 0, y, n) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        }
-    } else {
-        0
-    }
-}
-
-fn jump_n_southwest(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, -n, y, n) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        }
-    } else {
-        0
-    }
-}
-
-fn jump_n_west(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, -n, y, 0) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        }
-    } else {
-        0
-    }
-}
-
-fn jump_n_northwest(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, -n, y, -n) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        }
-    } else {
-        0
-    }
-}
-
-fn jump_knight(grid: &Grid, x: i64, y: i64) -> i64 {
-    jump_knight_northeast(grid, x, y) + jump_knight_northwest(grid, x, y)
-}
-
-fn jump_knight_northeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, 1, y, -2) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
+impl RayTable {
+    fn new(grid: &Grid) -> Self {
+        let board = grid.board();
+        let mut rays = Vec::with_capacity(board.area());
+        for y in 0..board.height() {
+            for x in 0..board.width() {
+                rays.push(
+                    DIRECTIONS.map(|d| grid.ray(x, y, d, reach(board, x as i64, y as i64, d))),
+                );
+            }
         }
-    } else {
-        0
+        RayTable { board, rays }
     }
-}
 
-fn jump_knight_northwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    if let Some((xp, yp)) = try_add(x, -1, y, -2) {
-        match grid.get(xp, yp) {
-            Square::Empty => 1,
-            Square::Friendly => 0,
-            Square::Opponent => 1,
+    // Moves starting off the board (as the dove's and jump-then-range's
+    // second legs may) have nowhere to go.
+    fn get(&self, x: i64, y: i64, direction: (i64, i64)) -> Ray {
+        let (width, height) = (self.board.width(), self.board.height());
+        if !(0..width as i64).contains(&x) || !(0..height as i64).contains(&y) {
+            return Ray::EMPTY;
         }
-    } else {
-        0
+        self.rays[y as usize * width + x as usize][direction_index(direction)]
     }
 }
 
-fn dove_moves(grid: &Grid, x: i64, y: i64) -> i64 {
-    dove_northeast(grid, x, y)
-        + dove_southeast(grid, x, y)
-        + dove_southwest(grid, x, y)
-        + dove_northwest(grid, x, y)
-}
-
-fn dove_northeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    if y > 3 {
-        step_n_northeast(grid, x + 3, y - 3, 3)
-    } else {
-        0
+// Moves along a ray that may pass over `jumps` pieces: every square up to the
+// next piece, plus that piece if it can be captured.
+fn slide(ray: Ray, jumps: u32) -> Count {
+    let mut blockers = ray.occupied();
+    for _ in 0..jumps {
+        blockers &= blockers.wrapping_sub(1);
     }
-}
-
-fn dove_southeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_southeast(grid, x + 3, y + 3, 3)
-}
-
-fn dove_southwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    if x > 3 {
-        step_n_southwest(grid, x - 3, y + 3, 3)
-    } else {
-        0
-    }
-}
-
-fn dove_northwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    if x > 3 && y > 3 {
-        step_n_west(grid, x - 3, y - 3, 3)
-    } else {
-        0
-    }
-}
-
-fn range_orthogonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    range_north(grid, x, y)
-        + range_east(grid, x, y)
-        + range_south(grid, x, y)
-        + range_west(grid, x, y)
-}
-
-fn range_diagonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    range_northeast(grid, x, y)
-        + range_southeast(grid, x, y)
-        + range_southwest(grid, x, y)
-        + range_northwest(grid, x, y)
-}
-
-fn range_north(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_north(grid, x, y, BOARD_HEIGHT as i64)
-}
-
-fn range_northeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_northeast(grid, x, y, BOARD_HEIGHT as i64)
-}
-
-fn range_east(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_east(grid, x, y, BOARD_HEIGHT as i64)
-}
-
-fn range_southeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_southeast(grid, x, y, BOARD_HEIGHT as i64)
-}
-
-fn range_south(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_south(grid, x, y, BOARD_HEIGHT as i64)
-}
-
-fn range_southwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_southwest(grid, x, y, BOARD_HEIGHT as i64)
-}
-
-fn range_west(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_west(grid, x, y, BOARD_HEIGHT as i64)
-}
-
-fn range_northwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    step_n_northwest(grid, x, y, BOARD_HEIGHT as i64)
-}
-
-fn flying_jump_orthogonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    flying_jump_north(grid, x, y, BOARD_HEIGHT as i64, 3)
-        + flying_jump_east(grid, x, y, BOARD_HEIGHT as i64, 3)
-        + flying_jump_south(grid, x, y, BOARD_HEIGHT as i64, 3)
-        + flying_jump_west(grid, x, y, BOARD_HEIGHT as i64, 3)
-}
-
-fn flying_jump_diagonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    flying_jump_northeast(grid, x, y, BOARD_HEIGHT as i64, 3)
-        + flying_jump_southeast(grid, x, y, BOARD_HEIGHT as i64, 3)
-        + flying_jump_southwest(grid, x, y, BOARD_HEIGHT as i64, 3)
-        + flying_jump_northwest(grid, x, y, BOARD_HEIGHT as i64, 3)
-}
-
-fn flying_jump_north(grid: &Grid, x: i64, y: i64, n: i64, jumps: i64) -> i64 {
-    match try_add(x, 0, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_jump_north(grid, xp, yp, n - 1, jumps),
-            Square::Friendly if jumps > 0 => 1 + flying_jump_north(grid, xp, yp, n - 1, jumps - 1),
-            Square::Friendly => 0,
-            Square::Opponent if jumps > 0 => 1 + flying_jump_north(grid, xp, yp, n - 1, jumps - 1),
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn flying_jump_northeast(grid: &Grid, x: i64, y: i64, n: i64, jumps: i64) -> i64 {
-    match try_add(x, 1, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_jump_northeast(grid, xp, yp, n - 1, jumps),
-            Square::Friendly if jumps > 0 => {
-                1 + flying_jump_northeast(grid, xp, yp, n - 1, jumps - 1)
-            }
-            Square::Friendly => 0,
-            Square::Opponent if jumps > 0 => {
-                1 + flying_jump_northeast(grid, xp, yp, n - 1, jumps - 1)
-            }
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn flying_jump_east(grid: &Grid, x: i64, y: i64, n: i64, jumps: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_jump_east(grid, xp, yp, n - 1, jumps),
-            Square::Friendly if jumps > 0 => 1 + flying_jump_east(grid, xp, yp, n - 1, jumps - 1),
-            Square::Friendly => 0,
-            Square::Opponent if jumps > 0 => 1 + flying_jump_east(grid, xp, yp, n - 1, jumps - 1),
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn flying_jump_southeast(grid: &Grid, x: i64, y: i64, n: i64, jumps: i64) -> i64 {
-    match try_add(x, 1, y, 1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_jump_southeast(grid, xp, yp, n - 1, jumps),
-            Square::Friendly if jumps > 0 => {
-                1 + flying_jump_southeast(grid, xp, yp, n - 1, jumps - 1)
-            }
-            Square::Friendly => 0,
-            Square::Opponent if jumps > 0 => {
-                1 + flying_jump_southeast(grid, xp, yp, n - 1, jumps - 1)
-            }
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn flying_jump_south(grid: &Grid, x: i64, y: i64, n: i64, jumps: i64) -> i64 {
-    match try_add(x, 0, y, 1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_jump_south(grid, xp, yp, n - 1, jumps),
-            Square::Friendly if jumps > 0 => 1 + flying_jump_south(grid, xp, yp, n - 1, jumps - 1),
-            Square::Friendly => 0,
-            Square::Opponent if jumps > 0 => 1 + flying_jump_south(grid, xp, yp, n - 1, jumps - 1),
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn flying_jump_southwest(grid: &Grid, x: i64, y: i64, n: i64, jumps: i64) -> i64 {
-    match try_add(x, -1, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_jump_southwest(grid, xp, yp, n - 1, jumps),
-            Square::Friendly if jumps > 0 => {
-                1 + flying_jump_southwest(grid, xp, yp, n - 1, jumps - 1)
-            }
-            Square::Friendly => 0,
-            Square::Opponent if jumps > 0 => {
-                1 + flying_jump_southwest(grid, xp, yp, n - 1, jumps - 1)
-            }
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn flying_jump_west(grid: &Grid, x: i64, y: i64, n: i64, jumps: i64) -> i64 {
-    match try_add(x, -1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_jump_west(grid, xp, yp, n - 1, jumps),
-            Square::Friendly if jumps > 0 => 1 + flying_jump_west(grid, xp, yp, n - 1, jumps - 1),
-            Square::Friendly => 0,
-            Square::Opponent if jumps > 0 => 1 + flying_jump_west(grid, xp, yp, n - 1, jumps - 1),
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn flying_jump_northwest(grid: &Grid, x: i64, y: i64, n: i64, jumps: i64) -> i64 {
-    match try_add(x, -1, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_jump_northwest(grid, xp, yp, n - 1, jumps),
-            Square::Friendly if jumps > 0 => {
-                1 + flying_jump_northwest(grid, xp, yp, n - 1, jumps - 1)
-            }
-            Square::Friendly => 0,
-            Square::Opponent if jumps > 0 => {
-                1 + flying_jump_northwest(grid, xp, yp, n - 1, jumps - 1)
-            }
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn flying_capture_orthogonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    flying_capture_north(grid, x, y)
-        + flying_capture_east(grid, x, y)
-        + flying_capture_south(grid, x, y)
-        + flying_capture_west(grid, x, y)
-}
-
-fn flying_capture_diagonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    flying_capture_northeast(grid, x, y)
-        + flying_capture_southeast(grid, x, y)
-        + flying_capture_southwest(grid, x, y)
-        + flying_capture_northwest(grid, x, y)
-}
-
-fn flying_capture_north(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 0, y, -1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_capture_north(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_north(grid, xp, yp),
-        },
-        _ => 0,
-    }
-}
-
-fn count_pieces_north(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 0, y, -1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 0 + count_pieces_north(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_north(grid, xp, yp),
-        },
-        _ => 0,
-    }
-}
-
-fn flying_capture_northeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 1, y, -1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_capture_northeast(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_northeast(grid, xp, yp),
-        },
-        _ => 0,
-    }
-}
-
-fn count_pieces_northeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 1, y, -1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 0 + count_pieces_northeast(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_northeast(grid, xp, yp),
-        },
-        _ => 0,
-    }
-}
-
-fn flying_capture_east(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_capture_east(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_east(grid, xp, yp),
-        },
-        _ => 0,
-    }
-}
-
-fn count_pieces_east(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 0 + count_pieces_east(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_east(grid, xp, yp),
-        },
-        _ => 0,
+    if blockers == 0 {
+        return Count {
+            quiet: ray.len as i64,
+            captures: 0,
+        };
     }
-}
 
-fn flying_capture_southeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 1, y, 1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_capture_southeast(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_southeast(grid, xp, yp),
-        },
-        _ => 0,
+    let first = blockers.trailing_zeros();
+    Count {
+        quiet: first as i64,
+        captures: (ray.opponent >> first & 1) as i64,
     }
 }
 
-fn count_pieces_southeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 1, y, 1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 0 + count_pieces_southeast(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_southeast(grid, xp, yp),
-        },
-        _ => 0,
-    }
+fn step_n(rays: &RayTable, x: i64, y: i64, direction: (i64, i64), n: u32) -> Count {
+    slide(rays.get(x, y, direction).truncate(n), 0)
 }
 
-fn flying_capture_south(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 0, y, 1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_capture_south(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_south(grid, xp, yp),
-        },
-        _ => 0,
-    }
+fn range(rays: &RayTable, x: i64, y: i64, direction: (i64, i64)) -> Count {
+    slide(rays.get(x, y, direction), 0)
 }
 
-fn count_pieces_south(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, 0, y, 1) {
+fn jump(grid: &Grid, x: i64, y: i64, dx: i64, dy: i64) -> Count {
+    match try_add(grid.board(), x, dx, y, dy) {
         Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 0 + count_pieces_south(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_south(grid, xp, yp),
+            Square::Empty => Count::QUIET,
+            Square::Opponent(_) => Count::CAPTURE,
+            Square::Friendly(_) => Count::NONE,
         },
-        _ => 0,
+        None => Count::NONE,
     }
 }
 
-fn flying_capture_southwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, -1, y, 1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_capture_southwest(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_southwest(grid, xp, yp),
-        },
-        _ => 0,
-    }
-}
-
-fn count_pieces_southwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, -1, y, 1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 0 + count_pieces_southwest(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_southwest(grid, xp, yp),
-        },
-        _ => 0,
-    }
-}
-
-fn flying_capture_west(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, -1, y, 0) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_capture_west(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_west(grid, xp, yp),
-        },
-        _ => 0,
-    }
-}
-
-fn count_pieces_west(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, -1, y, 0) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 0 + count_pieces_west(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_west(grid, xp, yp),
-        },
-        _ => 0,
+// Steps of up to three squares along each diagonal, starting three squares
+// out.
+fn dove_moves(rays: &RayTable, x: i64, y: i64) -> Count {
+    let mut total = step_n(rays, x + 3, y + 3, SOUTHEAST, 3);
+    if y > 3 {
+        total += step_n(rays, x + 3, y - 3, NORTHEAST, 3);
     }
-}
-
-fn flying_capture_northwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, -1, y, -1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 1 + flying_capture_northwest(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_northwest(grid, xp, yp),
-        },
-        _ => 0,
+    if x > 3 {
+        total += step_n(rays, x - 3, y + 3, SOUTHWEST, 3);
     }
-}
-
-fn count_pieces_northwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    match try_add(x, -1, y, -1) {
-        Some((xp, yp)) => match grid.get(xp, yp) {
-            Square::Empty => 0 + count_pieces_northwest(grid, xp, yp),
-            Square::Friendly | Square::Opponent => 1 + count_pieces_northwest(grid, xp, yp),
-        },
-        _ => 0,
+    if x > 3 && y > 3 {
+        total += step_n(rays, x - 3, y - 3, NORTHWEST, 3);
     }
+    total
 }
 
-fn calc_full_lion(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
+// Flies over up to three pieces, landing on any empty square or capturing any
+// opponent up to and including the fourth piece; friendly pieces are flown
+// over but cannot be landed on.
+fn flying_jump(ray: Ray) -> Count {
+    let mut blockers = ray.occupied();
+    for _ in 0..3 {
+        blockers &= blockers.wrapping_sub(1);
+    }
+    let reach = match blockers {
+        0 => ray.len,
+        blockers => blockers.trailing_zeros() + 1,
+    };
+    let passed = 1u64.checked_shl(reach).map_or(u64::MAX, |bit| bit - 1);
+    Count {
+        quiet: (reach - (ray.occupied() & passed).count_ones()) as i64,
+        captures: (ray.opponent & passed).count_ones() as i64,
+    }
+}
+
+// Every empty square up to the first piece, then every opponent along the
+// ray; friendly pieces are flown over but cannot be captured.
+fn flying_capture(ray: Ray) -> Count {
+    let quiet = match ray.occupied() {
+        0 => ray.len,
+        occupied => occupied.trailing_zeros(),
+    };
+    Count {
+        quiet: quiet as i64,
+        captures: ray.opponent.count_ones() as i64,
+    }
+}
+
+// Flies over and captures every piece short of the first one in `senior`, the
+// pieces ranked too high to pass, which it can capture only if it is an
+// opponent.
+fn range_capture(ray: Ray, senior: u64) -> Count {
+    let reach = match senior {
+        0 => ray.len,
+        senior => senior.trailing_zeros(),
+    };
+    let passed = 1u64.checked_shl(reach).map_or(u64::MAX, |bit| bit - 1);
+    let blocker = ray.opponent & senior & senior.wrapping_neg();
+    Count {
+        quiet: (reach - (ray.occupied() & passed).count_ones()) as i64,
+        captures: ((ray.opponent & passed).count_ones() + blocker.count_ones()) as i64,
+    }
+}
+
+fn calc_full_lion(grid: &Grid, x: i64, y: i64) -> Count {
+    let mut total = Count::NONE;
     for j in -2..=2 {
         for i in -2..=2 {
             if i == 0 && j == 0 {
                 continue;
             }
-            match try_add(x, j, y, i) {
-                Some((xp, yp)) => match grid.get(xp, yp) {
-                    Square::Empty | Square::Opponent => total += 1,
-                    Square::Friendly => {}
-                },
-                _ => {}
-            }
+            total += jump(grid, x, y, j, i);
         }
     }
-    if total > 0 {
-        total += 1;
+    // Staying put by moving away and back again.
+    if total.total() > 0 {
+        total += Count::QUIET;
     }
     total
 }
 
-fn calc_limited_lion(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total: i64 = 0;
+fn calc_limited_lion(grid: &Grid, x: i64, y: i64) -> Count {
+    let mut total = Count::NONE;
     for n in 1..=3 {
-        total += jump_n_orthogonal(grid, x, y, n);
-        total += jump_n_diagonal(grid, x, y, n);
-    }
-    if total > 0 {
-        total += 1;
-    }
-    return total;
-}
-
-fn hook_orthogonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    hook_north(grid, x, y, 36)
-        + hook_east(grid, x, y, 36)
-        + hook_south(grid, x, y, 36)
-        + hook_west(grid, x, y, 36)
-}
-
-fn hook_diagonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    hook_northeast(grid, x, y, 36)
-        + hook_southeast(grid, x, y, 36)
-        + hook_southwest(grid, x, y, 36)
-        + hook_northwest(grid, x, y, 36)
-}
-
-fn hook_north(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 0, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => {
-                1 + hook_north(grid, xp, yp, n - 1)
-                    + step_n_east(grid, xp, yp, 36)
-                    + step_n_west(grid, xp, yp, 36)
-            }
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn hook_northeast(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, -1) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => {
-                1 + hook_northeast(grid, xp, yp, n - 1)
-                    + step_n_northwest(grid, xp, yp, 36)
-                    + step_n_southeast(grid, xp, yp, 36)
-            }
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn hook_east(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => {
-                1 + hook_east(grid, xp, yp, n - 1)
-                    + step_n_north(grid, xp, yp, 36)
-                    + step_n_south(grid, xp, yp, 36)
-            }
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn hook_southeast(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => {
-                1 + hook_southeast(grid, xp, yp, n - 1)
-                    + step_n_northeast(grid, xp, yp, 36)
-                    + step_n_southwest(grid, xp, yp, 36)
-            }
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
-
-fn hook_south(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => {
-                1 + hook_south(grid, xp, yp, n - 1)
-                    + step_n_east(grid, xp, yp, 36)
-                    + step_n_west(grid, xp, yp, 36)
-            }
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
+        for (dx, dy) in DIRECTIONS {
+            total += jump(grid, x, y, n * dx, n * dy);
+        }
     }
-}
-
-fn hook_southwest(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => {
-                1 + hook_southwest(grid, xp, yp, n - 1)
-                    + step_n_southeast(grid, xp, yp, 36)
-                    + step_n_northwest(grid, xp, yp, 36)
-            }
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
+    // Staying put by moving away and back again.
+    if total.total() > 0 {
+        total += Count::QUIET;
     }
+    total
 }
 
-fn hook_west(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => {
-                1 + hook_west(grid, xp, yp, n - 1)
-                    + step_n_south(grid, xp, yp, 36)
-                    + step_n_north(grid, xp, yp, 36)
-            }
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
-    }
-}
+// A range move along `direction` that may turn at right angles, either way,
+// at any empty square it passes.
+fn hook(rays: &RayTable, x: i64, y: i64, direction: (i64, i64)) -> Count {
+    let ray = rays.get(x, y, direction);
+    let (dx, dy) = direction;
+    let sides = [(-dy, dx), (dy, -dx)];
+    let free = match ray.occupied() {
+        0 => ray.len,
+        occupied => occupied.trailing_zeros(),
+    } as i64;
 
-fn hook_northwest(grid: &Grid, x: i64, y: i64, n: i64) -> i64 {
-    match try_add(x, 1, y, 0) {
-        Some((xp, yp)) if n > 0 => match grid.get(xp, yp) {
-            Square::Empty => {
-                1 + hook_northwest(grid, xp, yp, n - 1)
-                    + step_n_southwest(grid, xp, yp, 36)
-                    + step_n_northeast(grid, xp, yp, 36)
-            }
-            Square::Friendly => 0,
-            Square::Opponent => 1,
-        },
-        _ => 0,
+    let mut total = slide(ray, 0);
+    for k in 1..=free {
+        let (xp, yp) = (x + k * dx, y + k * dy);
+        total += range(rays, xp, yp, sides[0]) + range(rays, xp, yp, sides[1]);
     }
+    total
 }
 
-fn jump_then_range_orthogonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    jump_then_range_north(grid, x, y)
-        + jump_then_range_east(grid, x, y)
-        + jump_then_range_south(grid, x, y)
-        + jump_then_range_west(grid, x, y)
-}
-
-fn jump_then_range_diagonal(grid: &Grid, x: i64, y: i64) -> i64 {
-    jump_then_range_northeast(grid, x, y)
-        + jump_then_range_southeast(grid, x, y)
-        + jump_then_range_southwest(grid, x, y)
-        + jump_then_range_northwest(grid, x, y)
-}
-
-fn jump_then_range_north(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
-    total += step_n_north(grid, x, y, 36);
-    total += step_n_north(grid, x, y - 1, 36);
-
-    return total;
-}
-
-fn jump_then_range_northeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
-    total += step_n_northeast(grid, x, y, 36);
-    total += step_n_northeast(grid, x + 1, y - 1, 36);
-
-    return total;
-}
-
-fn jump_then_range_east(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
-    total += step_n_east(grid, x, y, 36);
-    total += step_n_east(grid, x + 1, y, 36);
-
-    return total;
-}
-
-fn jump_then_range_southeast(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
-    total += step_n_east(grid, x, y, 36);
-    total += step_n_east(grid, x + 1, y + 1, 36);
-
-    return total;
-}
-
-fn jump_then_range_south(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
-    total += step_n_south(grid, x, y, 36);
-    total += step_n_south(grid, x, y + 1, 36);
-
-    return total;
-}
-
-fn jump_then_range_southwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
-    total += step_n_southwest(grid, x, y, 36);
-    total += step_n_southwest(grid, x - 1, y + 1, 36);
-
-    return total;
-}
-
-fn jump_then_range_west(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
-    total += step_n_west(grid, x, y, 36);
-    total += step_n_west(grid, x - 1, y, 36);
-
-    return total;
-}
-
-fn jump_then_range_northwest(grid: &Grid, x: i64, y: i64) -> i64 {
-    let mut total = 0;
-    total += step_n_west(grid, x, y, 36);
-    total += step_n_west(grid, x - 1, y - 1, 36);
-
-    return total;
+// A range move along `direction` counted from both the starting square and the
+// square one step along it.
+fn jump_then_range(rays: &RayTable, x: i64, y: i64, direction: (i64, i64)) -> Count {
+    let (dx, dy) = direction;
+    range(rays, x, y, direction) + range(rays, x + dx, y + dy, direction)
 }
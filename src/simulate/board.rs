@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Each side of the board is one bitboard word.
+pub const MAX_BOARD_SIZE: usize = 64;
+const MIN_BOARD_SIZE: usize = 3;
+
+// Taikyoku shogi starts with 402 pieces a side on its 36x36 board; other
+// boards are filled with the same density.
+pub(super) const TAIKYOKU_PIECES_PER_SIDE: usize = 402;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Board {
+    width: usize,
+    height: usize,
+}
+
+impl Board {
+    pub const TAIKYOKU: Board = Board {
+        width: 36,
+        height: 36,
+    };
+
+    // The boards of the large shogi variants, smallest first.
+    pub const PRESETS: [(&'static str, Board); 7] = [
+        ("chu", Board::square(12)),
+        ("dai", Board::square(15)),
+        ("tenjiku", Board::square(16)),
+        ("dai-dai", Board::square(17)),
+        ("maka-dai-dai", Board::square(19)),
+        ("tai", Board::square(25)),
+        ("taikyoku", Board::TAIKYOKU),
+    ];
+
+    const fn square(size: usize) -> Board {
+        Board {
+            width: size,
+            height: size,
+        }
+    }
+
+    pub fn new(width: usize, height: usize) -> Result<Board, BoardError> {
+        let sizes = MIN_BOARD_SIZE..=MAX_BOARD_SIZE;
+        if sizes.contains(&width) && sizes.contains(&height) {
+            Ok(Board { width, height })
+        } else {
+            Err(BoardError::Size { width, height })
+        }
+    }
+
+    pub fn preset(name: &str) -> Option<Board> {
+        Board::PRESETS
+            .iter()
+            .find(|(preset, _)| preset.eq_ignore_ascii_case(name))
+            .map(|&(_, board)| board)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn area(&self) -> usize {
+        self.width * self.height
+    }
+
+    // The most pieces either side has in a simulated position, scaled from
+    // taikyoku shogi by area and rounded.
+    pub fn pieces_per_side(&self) -> usize {
+        let taikyoku = Board::TAIKYOKU.area();
+        ((TAIKYOKU_PIECES_PER_SIDE * self.area() + taikyoku / 2) / taikyoku).max(2)
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board::TAIKYOKU
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+// Either a preset name or `WIDTHxHEIGHT`.
+impl FromStr for Board {
+    type Err = BoardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(board) = Board::preset(s) {
+            return Ok(board);
+        }
+        let unknown = || BoardError::Unknown(s.to_string());
+        let (width, height) = s.split_once(['x', 'X']).ok_or_else(unknown)?;
+        let width = width.trim().parse().map_err(|_| unknown())?;
+        let height = height.trim().parse().map_err(|_| unknown())?;
+        Board::new(width, height)
+    }
+}
+
+#[derive(Debug)]
+pub enum BoardError {
+    Size { width: usize, height: usize },
+    Unknown(String),
+}
+
+impl Display for BoardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoardError::Size { width, height } => write!(
+                f,
+                "a {width}x{height} board is not supported: each side must be between \
+                 {MIN_BOARD_SIZE} and {MAX_BOARD_SIZE} squares"
+            ),
+            BoardError::Unknown(s) => {
+                let presets: Vec<&str> = Board::PRESETS.iter().map(|(name, _)| *name).collect();
+                write!(
+                    f,
+                    "'{s}' is neither WIDTHxHEIGHT nor one of {}",
+                    presets.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
@@ -1,39 +1,167 @@
 use rand::Rng;
 use std::fmt::{Debug, Display};
+use std::ops::Range;
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+use super::board::{Board, MAX_BOARD_SIZE};
+use crate::face::Rank;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Square {
     Empty,
-    Friendly,
-    Opponent,
+    Friendly(Rank),
+    Opponent(Rank),
+}
+
+impl Square {
+    // Empty squares count as ordinary, so range captures pass them freely.
+    pub fn rank(&self) -> Rank {
+        match self {
+            Square::Empty => Rank::Ordinary,
+            Square::Friendly(rank) | Square::Opponent(rank) => *rank,
+        }
+    }
 }
 
 impl Display for Square {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let c = match self {
             Square::Empty => ' ',
-            Square::Friendly => 'F',
-            Square::Opponent => 'O',
+            Square::Friendly(_) => 'F',
+            Square::Opponent(_) => 'O',
         };
         write!(f, "{}", c)
     }
 }
 
-pub const BOARD_WIDTH: usize = 36;
-pub const BOARD_HEIGHT: usize = 36;
+const NUM_DIAGONALS: usize = 2 * MAX_BOARD_SIZE - 1;
+
+// The occupancy of one side, stored once per line direction so that any ray
+// from a square is a shift of a single word. Rows and both kinds of diagonal
+// are indexed by file, columns by rank. The lines are sized for the largest
+// board, so any smaller one fits.
+#[derive(Clone)]
+struct Bitboard {
+    rows: [u64; MAX_BOARD_SIZE],
+    columns: [u64; MAX_BOARD_SIZE],
+    // x - y is constant, running from northwest to southeast.
+    diagonals: [u64; NUM_DIAGONALS],
+    // x + y is constant, running from southwest to northeast.
+    anti_diagonals: [u64; NUM_DIAGONALS],
+}
+
+impl Bitboard {
+    fn new() -> Self {
+        Bitboard {
+            rows: [0; MAX_BOARD_SIZE],
+            columns: [0; MAX_BOARD_SIZE],
+            diagonals: [0; NUM_DIAGONALS],
+            anti_diagonals: [0; NUM_DIAGONALS],
+        }
+    }
+
+    fn contains(&self, x: usize, y: usize) -> bool {
+        self.rows[y] & 1 << x != 0
+    }
+
+    fn insert(&mut self, x: usize, y: usize) {
+        self.rows[y] |= 1 << x;
+        self.columns[x] |= 1 << y;
+        self.diagonals[x + MAX_BOARD_SIZE - 1 - y] |= 1 << x;
+        self.anti_diagonals[x + y] |= 1 << x;
+    }
+
+    fn remove(&mut self, x: usize, y: usize) {
+        self.rows[y] &= !(1 << x);
+        self.columns[x] &= !(1 << y);
+        self.diagonals[x + MAX_BOARD_SIZE - 1 - y] &= !(1 << x);
+        self.anti_diagonals[x + y] &= !(1 << x);
+    }
+
+    // Bit `k` is the square `k + 1` steps away from (x, y) along `direction`.
+    fn ray(&self, x: usize, y: usize, (dx, dy): (i64, i64), len: u32) -> u64 {
+        let (line, position) = match (dx, dy) {
+            (_, 0) => (self.rows[y], x),
+            (0, _) => (self.columns[x], y),
+            _ if dx == dy => (self.diagonals[x + MAX_BOARD_SIZE - 1 - y], x),
+            _ => (self.anti_diagonals[x + y], x),
+        };
+        let forward = if dx != 0 { dx > 0 } else { dy > 0 };
+        let ray = if forward {
+            line.checked_shr(position as u32 + 1).unwrap_or(0)
+        } else if position == 0 {
+            0
+        } else {
+            (line << (64 - position)).reverse_bits()
+        };
+        ray & ray_mask(len)
+    }
+}
+
+fn ray_mask(len: u32) -> u64 {
+    const MASKS: [u64; 65] = {
+        let mut masks = [u64::MAX; 65];
+        let mut len = 0;
+        while len < 64 {
+            masks[len] = (1 << len) - 1;
+            len += 1;
+        }
+        masks
+    };
+    MASKS[len as usize]
+}
+
+// The pieces met along a ray of `len` squares, nearest first.
+#[derive(Copy, Clone)]
+pub struct Ray {
+    pub len: u32,
+    pub friendly: u64,
+    pub opponent: u64,
+}
+
+impl Ray {
+    pub const EMPTY: Ray = Ray {
+        len: 0,
+        friendly: 0,
+        opponent: 0,
+    };
+
+    pub fn occupied(&self) -> u64 {
+        self.friendly | self.opponent
+    }
+
+    pub fn truncate(self, len: u32) -> Ray {
+        let len = self.len.min(len);
+        Ray {
+            len,
+            friendly: self.friendly & ray_mask(len),
+            opponent: self.opponent & ray_mask(len),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct Grid {
-    squares: [Square; BOARD_WIDTH * BOARD_HEIGHT],
+    board: Board,
+    friendly: Bitboard,
+    opponent: Bitboard,
+    // `senior[k]` holds the pieces of either side ranked above `Rank::ALL[k]`.
+    senior: [Bitboard; Rank::ALL.len() - 1],
 }
 
 impl Grid {
-    pub fn new() -> Self {
+    pub fn new(board: Board) -> Self {
         Grid {
-            squares: [Square::Empty; BOARD_WIDTH * BOARD_HEIGHT],
+            board,
+            friendly: Bitboard::new(),
+            opponent: Bitboard::new(),
+            senior: std::array::from_fn(|_| Bitboard::new()),
         }
     }
 
+    pub fn board(&self) -> Board {
+        self.board
+    }
+
     pub fn get<T, U>(&self, x: T, y: U) -> Square
     where
         T: TryInto<usize>,
@@ -43,7 +171,14 @@ impl Grid {
     {
         let x = x.try_into().unwrap();
         let y = y.try_into().unwrap();
-        self.squares[y * BOARD_WIDTH + x]
+        let rank = Rank::ALL[self.senior.iter().filter(|s| s.contains(x, y)).count()];
+        if self.friendly.contains(x, y) {
+            Square::Friendly(rank)
+        } else if self.opponent.contains(x, y) {
+            Square::Opponent(rank)
+        } else {
+            Square::Empty
+        }
     }
 
     pub fn set<T, U>(&mut self, square: Square, x: T, y: U)
@@ -55,27 +190,77 @@ impl Grid {
     {
         let x: usize = x.try_into().unwrap();
         let y: usize = y.try_into().unwrap();
-        self.squares[y * BOARD_WIDTH + x] = square;
+        self.friendly.remove(x, y);
+        self.opponent.remove(x, y);
+        match square {
+            Square::Empty => {}
+            Square::Friendly(_) => self.friendly.insert(x, y),
+            Square::Opponent(_) => self.opponent.insert(x, y),
+        }
+        let rank = square.rank().index();
+        for (k, senior) in self.senior.iter_mut().enumerate() {
+            if k < rank {
+                senior.insert(x, y);
+            } else {
+                senior.remove(x, y);
+            }
+        }
+    }
+
+    // The first `len` squares from (x, y) along `direction`, which must all be
+    // on the board.
+    pub(crate) fn ray(&self, x: usize, y: usize, direction: (i64, i64), len: u32) -> Ray {
+        Ray {
+            len,
+            friendly: self.friendly.ray(x, y, direction, len),
+            opponent: self.opponent.ray(x, y, direction, len),
+        }
+    }
+
+    // The pieces of either side ranked `rank` or above along the same ray.
+    pub(crate) fn ray_at_rank(
+        &self,
+        x: usize,
+        y: usize,
+        direction: (i64, i64),
+        len: u32,
+        rank: Rank,
+    ) -> u64 {
+        match rank.index() {
+            0 => self.ray(x, y, direction, len).occupied(),
+            k => self.senior[k - 1].ray(x, y, direction, len),
+        }
+    }
+
+    pub fn randomly_place(&mut self, rng: &mut (impl Rng + ?Sized), square: Square) {
+        self.randomly_place_in(rng, square, 0..self.board.height());
     }
 
-    pub fn randomly_place(&mut self, rng: &mut impl Rng, square: Square) {
-        let x = rng.gen_range(0..BOARD_WIDTH);
-        let y = rng.gen_range(0..BOARD_HEIGHT);
+    // Places `square` on a random empty square of the given ranks, which must
+    // have one.
+    pub fn randomly_place_in(
+        &mut self,
+        rng: &mut (impl Rng + ?Sized),
+        square: Square,
+        ranks: Range<usize>,
+    ) {
+        let x = rng.gen_range(0..self.board.width());
+        let y = rng.gen_range(ranks.clone());
         if self.get(x, y) == Square::Empty {
             self.set(square, x, y);
         } else {
-            self.randomly_place(rng, square);
+            self.randomly_place_in(rng, square, ranks);
         }
     }
 }
 
 impl Display for Grid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for x in 0..BOARD_WIDTH {
-            for y in 0..BOARD_HEIGHT {
+        for x in 0..self.board.width() {
+            for y in 0..self.board.height() {
                 write!(f, "|{}", self.get(x, y))?;
             }
-            write!(f, "|\n")?;
+            writeln!(f, "|")?;
         }
 
         Ok(())
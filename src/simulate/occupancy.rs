@@ -0,0 +1,505 @@
+use rand::seq::index;
+use rand::{Rng, RngCore};
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+use super::board::Board;
+use super::grid::{Grid, Square};
+use crate::face::{Face, Rank};
+use crate::variant::{starting_array, StartingArray};
+
+// Lays out the pieces of a simulated position. The name a model displays is
+// saved with results, so that runs with different models are never merged or
+// resumed into each other, and must change whenever its parameters do. Models
+// place ordinary pieces, which `Ranked` then gives ranks, unless they say they
+// rank their pieces themselves.
+pub trait OccupancyModel: Display + Send + Sync {
+    fn populate(&self, grid: &mut Grid, rng: &mut dyn RngCore);
+
+    fn ranks_pieces(&self) -> bool {
+        false
+    }
+}
+
+// How often each rank turns up among the pieces placed, as weights indexed by
+// `Rank::index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankMix {
+    weights: [usize; Rank::ALL.len()],
+}
+
+impl RankMix {
+    // Each face of the table counted once, since tables do not say how many
+    // of each piece an army holds.
+    pub fn of(faces: &[Face]) -> RankMix {
+        let mut weights = [0; Rank::ALL.len()];
+        for face in faces {
+            weights[face.rank.index()] += 1;
+        }
+        RankMix { weights }
+    }
+
+    // Highest rank first, so that the rare ranks take the lowest draws.
+    pub fn draw(&self, rng: &mut dyn RngCore) -> Rank {
+        let total: usize = self.weights.iter().sum();
+        if total == 0 {
+            return Rank::Ordinary;
+        }
+        let mut draw = rng.gen_range(0..total);
+        for rank in Rank::ALL.into_iter().rev() {
+            let weight = self.weights[rank.index()];
+            if draw < weight {
+                return rank;
+            }
+            draw -= weight;
+        }
+        Rank::Ordinary
+    }
+}
+
+// Lowest rank first, matching `Rank::ALL`.
+impl Display for RankMix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let weights: Vec<String> = self.weights.iter().map(|w| w.to_string()).collect();
+        write!(f, "{}", weights.join("/"))
+    }
+}
+
+// Anywhere from a handful of pieces to a full army a side, scattered over the
+// whole board.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uniform;
+
+impl OccupancyModel for Uniform {
+    fn populate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        let (friendlies, opponents) = uniform_counts(grid.board(), rng);
+        for _ in 0..friendlies {
+            grid.randomly_place(rng, Square::Friendly(Rank::Ordinary));
+        }
+        for _ in 0..opponents {
+            grid.randomly_place(rng, Square::Opponent(Rank::Ordinary));
+        }
+    }
+}
+
+impl Display for Uniform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "uniform")
+    }
+}
+
+fn uniform_counts(board: Board, rng: &mut dyn RngCore) -> (usize, usize) {
+    let per_side = board.pieces_per_side() as i32;
+    let num_pieces = rng.gen_range(3..2 * per_side);
+    let num_friendlies = rng.gen_range(1..i32::min(num_pieces - 1, per_side));
+    let num_opponents = i32::min(per_side, num_pieces - num_friendlies);
+    (num_friendlies as usize, num_opponents as usize)
+}
+
+// Each side's army in its own camp, friendly at the bottom and opponent at the
+// top, with up to `max_removed` of each army (drawn per trial) already
+// captured. With a starting `array` the pieces that remain stand where the
+// array puts them, with the ranks of their faces. Without one the camps are as
+// many ranks as the army needs, which is 12 on the taikyoku board as in the
+// real setup, and the pieces are placed at random within them.
+#[derive(Debug, Clone)]
+pub struct Setup {
+    pub max_removed: f64,
+    pub array: Option<StartingArray>,
+}
+
+impl OccupancyModel for Setup {
+    fn populate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        let board = grid.board();
+        let removed = rng.gen_range(0.0..=self.max_removed);
+        let (width, height) = (board.width(), board.height());
+        if let Some(array) = &self.array {
+            let pieces = &array.pieces;
+            let remaining = (pieces.len() as f64 * (1.0 - removed)).round() as usize;
+            for i in index::sample(rng, pieces.len(), remaining) {
+                let (file, rank, face) = &pieces[i];
+                grid.set(Square::Friendly(face.rank), *file, height - 1 - rank);
+            }
+            for i in index::sample(rng, pieces.len(), remaining) {
+                let (file, rank, face) = &pieces[i];
+                grid.set(Square::Opponent(face.rank), width - 1 - file, *rank);
+            }
+            return;
+        }
+
+        let ranks = camp_ranks(board);
+        let per_side = board.pieces_per_side().min(ranks * width);
+        let remaining = (per_side as f64 * (1.0 - removed)).round() as usize;
+        for _ in 0..remaining {
+            grid.randomly_place_in(
+                rng,
+                Square::Friendly(Rank::Ordinary),
+                height - ranks..height,
+            );
+        }
+        for _ in 0..remaining {
+            grid.randomly_place_in(rng, Square::Opponent(Rank::Ordinary), 0..ranks);
+        }
+    }
+
+    fn ranks_pieces(&self) -> bool {
+        self.array.is_some()
+    }
+}
+
+impl Display for Setup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.max_removed {
+            0.0 => write!(f, "setup"),
+            max_removed => write!(f, "setup:{max_removed}"),
+        }
+    }
+}
+
+// Enough ranks to hold a full army, leaving at least one empty rank between
+// the camps.
+fn camp_ranks(board: Board) -> usize {
+    board
+        .pieces_per_side()
+        .div_ceil(board.width())
+        .min((board.height() - 1) / 2)
+}
+
+// The same share of the board occupied in every trial, split evenly between
+// the sides and scattered over the whole board.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedDensity {
+    pub density: f64,
+}
+
+impl OccupancyModel for FixedDensity {
+    fn populate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        let area = grid.board().area();
+        let pieces = ((self.density * area as f64).round() as usize).clamp(2, area);
+        for _ in 0..pieces / 2 {
+            grid.randomly_place(rng, Square::Friendly(Rank::Ordinary));
+        }
+        for _ in pieces / 2..pieces {
+            grid.randomly_place(rng, Square::Opponent(Rank::Ordinary));
+        }
+    }
+}
+
+impl Display for FixedDensity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "density:{}", self.density)
+    }
+}
+
+// Positions from a stretch of a game, each trial at a uniformly random moment
+// of it. Both sides keep the share of their army given by `remaining` at that
+// moment and are scattered over the whole board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamePhase {
+    Opening,
+    Middlegame,
+    Endgame,
+    WholeGame,
+}
+
+impl GamePhase {
+    // The stretch of the game, from 0 at the start to 1 at the end.
+    fn moments(&self) -> Range<f64> {
+        match self {
+            GamePhase::Opening => 0.0..1.0 / 3.0,
+            GamePhase::Middlegame => 1.0 / 3.0..2.0 / 3.0,
+            GamePhase::Endgame => 2.0 / 3.0..1.0,
+            GamePhase::WholeGame => 0.0..1.0,
+        }
+    }
+}
+
+// An assumed attrition curve rather than one measured from games: armies are
+// whole at the start, thin out fastest in the middlegame, and are down to a
+// twentieth at the end.
+fn remaining(moment: f64) -> f64 {
+    let exchanged = moment * moment * (3.0 - 2.0 * moment);
+    1.0 - 0.95 * exchanged
+}
+
+impl OccupancyModel for GamePhase {
+    fn populate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        let moment = rng.gen_range(self.moments());
+        let per_side = grid.board().pieces_per_side() as f64;
+        let pieces = ((per_side * remaining(moment)).round() as usize).max(1);
+        for _ in 0..pieces {
+            grid.randomly_place(rng, Square::Friendly(Rank::Ordinary));
+        }
+        for _ in 0..pieces {
+            grid.randomly_place(rng, Square::Opponent(Rank::Ordinary));
+        }
+    }
+}
+
+impl Display for GamePhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GamePhase::Opening => write!(f, "phase:opening"),
+            GamePhase::Middlegame => write!(f, "phase:middlegame"),
+            GamePhase::Endgame => write!(f, "phase:endgame"),
+            GamePhase::WholeGame => write!(f, "phase"),
+        }
+    }
+}
+
+// As many pieces as the uniform model, but each side crowds towards its own
+// edge: a piece is found `d` ranks from its edge with probability falling
+// linearly in `d`, so territory is held without a hard camp boundary.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Territorial;
+
+impl OccupancyModel for Territorial {
+    fn populate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        let (friendlies, opponents) = uniform_counts(grid.board(), rng);
+        for _ in 0..friendlies {
+            place_near_edge(grid, rng, Square::Friendly(Rank::Ordinary));
+        }
+        for _ in 0..opponents {
+            place_near_edge(grid, rng, Square::Opponent(Rank::Ordinary));
+        }
+    }
+}
+
+impl Display for Territorial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "territorial")
+    }
+}
+
+fn place_near_edge(grid: &mut Grid, rng: &mut dyn RngCore, square: Square) {
+    let board = grid.board();
+    loop {
+        let x = rng.gen_range(0..board.width());
+        let distance = rng
+            .gen_range(0..board.height())
+            .min(rng.gen_range(0..board.height()));
+        let y = match square {
+            Square::Opponent(_) => distance,
+            _ => board.height() - 1 - distance,
+        };
+        if grid.get(x, y) == Square::Empty {
+            grid.set(square, x, y);
+            return;
+        }
+    }
+}
+
+// Positions read from a file, one picked at random for each trial.
+//
+// Positions are separated by blank lines and written one rank per line, top
+// rank first, with `F` for a friendly piece, `O` for an opponent and `.` for
+// an empty square. Spaces within a rank are ignored and lines starting with
+// `#` are comments. Every position must fill the board exactly. Replayed pieces
+// are all ordinary, so range captures can pass any of them, unless `Ranked`
+// ranks them.
+#[derive(Clone)]
+pub struct Replay {
+    name: String,
+    positions: Vec<Grid>,
+}
+
+impl Replay {
+    pub fn load(path: impl AsRef<Path>, board: Board) -> Result<Replay, OccupancyError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        Replay::parse(&format!("replay:{}", path.display()), &text, board)
+    }
+
+    pub fn parse(name: &str, text: &str, board: Board) -> Result<Replay, OccupancyError> {
+        let mut positions = Vec::new();
+        let mut ranks: Vec<(usize, String)> = Vec::new();
+        let lines = text.lines().chain([""]).enumerate();
+        for (i, line) in lines.filter(|(_, line)| !line.starts_with('#')) {
+            let rank: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+            if !rank.is_empty() {
+                ranks.push((i + 1, rank));
+            } else if !ranks.is_empty() {
+                positions.push(parse_position(&ranks, board)?);
+                ranks.clear();
+            }
+        }
+        if positions.is_empty() {
+            return Err(OccupancyError::NoPositions);
+        }
+
+        Ok(Replay {
+            name: name.to_string(),
+            positions,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+}
+
+fn parse_position(ranks: &[(usize, String)], board: Board) -> Result<Grid, OccupancyError> {
+    let (first, _) = ranks[0];
+    if ranks.len() != board.height() {
+        return Err(OccupancyError::Position {
+            line: first,
+            message: format!(
+                "the position has {} ranks instead of {}",
+                ranks.len(),
+                board.height()
+            ),
+        });
+    }
+
+    let mut grid = Grid::new(board);
+    for (y, (line, rank)) in ranks.iter().enumerate() {
+        if rank.chars().count() != board.width() {
+            return Err(OccupancyError::Position {
+                line: *line,
+                message: format!(
+                    "the rank has {} squares instead of {}",
+                    rank.chars().count(),
+                    board.width()
+                ),
+            });
+        }
+        for (x, c) in rank.chars().enumerate() {
+            let square = match c {
+                'F' => Square::Friendly(Rank::Ordinary),
+                'O' => Square::Opponent(Rank::Ordinary),
+                '.' => Square::Empty,
+                _ => {
+                    return Err(OccupancyError::Position {
+                        line: *line,
+                        message: format!("'{c}' is not F, O or ."),
+                    })
+                }
+            };
+            grid.set(square, x, y);
+        }
+    }
+    Ok(grid)
+}
+
+impl OccupancyModel for Replay {
+    fn populate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        let position = &self.positions[rng.gen_range(0..self.positions.len())];
+        assert_eq!(
+            grid.board(),
+            position.board(),
+            "replayed positions are for another board"
+        );
+        *grid = position.clone();
+    }
+}
+
+impl Display for Replay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+// Lays out the pieces with `model`, then ranks every one of them from `ranks`,
+// so that range captures meet pieces as senior as those of the face table
+// being valued. Pieces a model has ranked itself keep their ranks.
+pub struct Ranked {
+    pub model: Box<dyn OccupancyModel>,
+    pub ranks: RankMix,
+}
+
+impl OccupancyModel for Ranked {
+    fn populate(&self, grid: &mut Grid, rng: &mut dyn RngCore) {
+        self.model.populate(grid, rng);
+        if self.model.ranks_pieces() {
+            return;
+        }
+        let board = grid.board();
+        for y in 0..board.height() {
+            for x in 0..board.width() {
+                match grid.get(x, y) {
+                    Square::Empty => {}
+                    Square::Friendly(_) => grid.set(Square::Friendly(self.ranks.draw(rng)), x, y),
+                    Square::Opponent(_) => grid.set(Square::Opponent(self.ranks.draw(rng)), x, y),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Ranked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, ranked {}", self.model, self.ranks)
+    }
+}
+
+// Builds a model from its name: `uniform`, `setup[:FRACTION]`, `density:FRACTION`,
+// `phase[:opening|middlegame|endgame]`, `territorial` or `replay:FILE`. Replayed
+// positions are checked against `board`, and `setup` uses the starting array of
+// the built-in variant played on it, if there is one.
+pub fn occupancy_model(s: &str, board: Board) -> Result<Box<dyn OccupancyModel>, OccupancyError> {
+    let fraction = |fraction: &str| match fraction.parse() {
+        Ok(f) if (0.0..=1.0).contains(&f) => Ok(f),
+        _ => Err(OccupancyError::Fraction(fraction.to_string())),
+    };
+    Ok(match s.split_once(':') {
+        None if s == "uniform" => Box::new(Uniform),
+        None if s == "setup" => Box::new(Setup {
+            max_removed: 0.0,
+            array: starting_array(board),
+        }),
+        None if s == "phase" => Box::new(GamePhase::WholeGame),
+        None if s == "territorial" => Box::new(Territorial),
+        Some(("setup", f)) => Box::new(Setup {
+            max_removed: fraction(f)?,
+            array: starting_array(board),
+        }),
+        Some(("density", f)) => Box::new(FixedDensity {
+            density: fraction(f)?,
+        }),
+        Some(("phase", "opening")) => Box::new(GamePhase::Opening),
+        Some(("phase", "middlegame")) => Box::new(GamePhase::Middlegame),
+        Some(("phase", "endgame")) => Box::new(GamePhase::Endgame),
+        Some(("replay", path)) => Box::new(Replay::load(path, board)?),
+        _ => return Err(OccupancyError::Unknown(s.to_string())),
+    })
+}
+
+#[derive(Debug)]
+pub enum OccupancyError {
+    Unknown(String),
+    Fraction(String),
+    Io(io::Error),
+    Position { line: usize, message: String },
+    NoPositions,
+}
+
+impl Display for OccupancyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OccupancyError::Unknown(s) => write!(
+                f,
+                "'{s}' is not an occupancy model: expected uniform, setup[:FRACTION], \
+                 density:FRACTION, phase[:opening|middlegame|endgame], territorial or replay:FILE"
+            ),
+            OccupancyError::Fraction(s) => write!(f, "'{s}' is not a fraction between 0 and 1"),
+            OccupancyError::Io(e) => write!(f, "{e}"),
+            OccupancyError::Position { line, message } => write!(f, "line {line}: {message}"),
+            OccupancyError::NoPositions => write!(f, "there are no positions to replay"),
+        }
+    }
+}
+
+impl std::error::Error for OccupancyError {}
+
+impl From<io::Error> for OccupancyError {
+    fn from(e: io::Error) -> Self {
+        OccupancyError::Io(e)
+    }
+}
@@ -1,85 +1,194 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::ops::{Add, AddAssign, Div};
 
-#[derive(Debug, Clone)]
+use crate::face::{Direction, Rank};
+
+// Quiet moves and captures are tallied apart, so that captures can be valued
+// differently from plain mobility.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Simulation {
-    pub orthogonal_steps: [f64; 8],
-    pub diagonal_steps: [f64; 8],
-    pub orthogonal_jumps: [f64; 4],
-    pub diagonal_jumps: [f64; 4],
+    pub quiet: Mobility,
+    pub captures: Mobility,
+}
+
+impl Simulation {
+    pub fn new() -> Self {
+        Simulation {
+            quiet: Mobility::new(),
+            captures: Mobility::new(),
+        }
+    }
+
+    pub fn pawn(&self) -> f64 {
+        let north = Direction::North.index();
+        self.quiet.steps[north][1] + self.captures.steps[north][1]
+    }
+
+    pub const NUM_FIELDS: usize = 2 * Mobility::NUM_FIELDS;
+
+    pub fn to_fields(&self) -> Vec<f64> {
+        let mut fields = self.quiet.to_fields();
+        fields.extend(self.captures.to_fields());
+        fields
+    }
+
+    pub fn field_names() -> Vec<String> {
+        ["quiet", "captures"]
+            .iter()
+            .flat_map(|kind| {
+                Mobility::field_names()
+                    .into_iter()
+                    .map(move |name| format!("{kind}.{name}"))
+            })
+            .collect()
+    }
+
+    pub fn from_fields(fields: &[f64]) -> Self {
+        assert_eq!(fields.len(), Self::NUM_FIELDS);
+        let (quiet, captures) = fields.split_at(Mobility::NUM_FIELDS);
+        Simulation {
+            quiet: Mobility::from_fields(quiet),
+            captures: Mobility::from_fields(captures),
+        }
+    }
+}
+
+// Mobility summed over the squares of a position. Every directional kind of
+// move is counted once per direction, indexed by `Direction::index`, so that a
+// forward move can be worth more than a backward one when the position is not
+// symmetric.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mobility {
+    pub steps: [[f64; 8]; 8],
+    pub jumps: [[f64; 4]; 8],
+    pub range: [f64; 8],
+    pub knight_forward: f64,
+    pub knight_backward: f64,
     pub dove: f64,
-    pub orthogonal_range: f64,
-    pub diagonal_range: f64,
-    pub knight_jump: f64,
-    pub orthogonal_flying_jump: f64,
-    pub diagonal_flying_jump: f64,
-    pub orthogonal_flying_capture: f64,
-    pub diagonal_flying_capture: f64,
-    pub orthogonal_jump_then_range: f64,
-    pub diagonal_jump_then_range: f64,
-    pub orthogonal_hook: f64,
-    pub diagonal_hook: f64,
+    pub flying_jump: [f64; 8],
+    pub flying_capture: [f64; 8],
+    pub jump_then_range: [f64; 8],
+    pub hook: [f64; 8],
+    // Indexed by the rank of the moving face, then by direction.
+    pub range_capture: [[f64; 8]; Rank::ALL.len()],
     pub full_lion: f64,
     pub limited_lion: f64,
 }
 
-impl Simulation {
+impl Mobility {
     pub fn new() -> Self {
-        Simulation {
-            orthogonal_steps: [0.0; 8],
-            diagonal_steps: [0.0; 8],
+        Mobility {
+            steps: [[0.0; 8]; 8],
+            jumps: [[0.0; 4]; 8],
+            range: [0.0; 8],
+            knight_forward: 0.0,
+            knight_backward: 0.0,
             dove: 0.0,
-            orthogonal_jumps: [0.0; 4],
-            diagonal_jumps: [0.0; 4],
-            orthogonal_range: 0.0,
-            diagonal_range: 0.0,
-            knight_jump: 0.0,
-            orthogonal_flying_jump: 0.0,
-            diagonal_flying_jump: 0.0,
-            orthogonal_flying_capture: 0.0,
-            diagonal_flying_capture: 0.0,
-            orthogonal_jump_then_range: 0.0,
-            diagonal_jump_then_range: 0.0,
-            orthogonal_hook: 0.0,
-            diagonal_hook: 0.0,
+            flying_jump: [0.0; 8],
+            flying_capture: [0.0; 8],
+            jump_then_range: [0.0; 8],
+            hook: [0.0; 8],
+            range_capture: [[0.0; 8]; Rank::ALL.len()],
             full_lion: 0.0,
             limited_lion: 0.0,
         }
     }
 
-    pub fn pawn(&self) -> f64 {
-        self.orthogonal_steps[1]
+    // In the order of `to_fields`.
+    pub const NUM_FIELDS: usize =
+        Direction::ALL.len() * (8 + 4 + 1) + 3 + Direction::ALL.len() * (4 + Rank::ALL.len()) + 2;
+
+    pub fn to_fields(&self) -> Vec<f64> {
+        let mut fields = Vec::with_capacity(Self::NUM_FIELDS);
+        for steps in &self.steps {
+            fields.extend_from_slice(steps);
+        }
+        for jumps in &self.jumps {
+            fields.extend_from_slice(jumps);
+        }
+        fields.extend_from_slice(&self.range);
+        fields.extend_from_slice(&[self.knight_forward, self.knight_backward, self.dove]);
+        fields.extend_from_slice(&self.flying_jump);
+        fields.extend_from_slice(&self.flying_capture);
+        fields.extend_from_slice(&self.jump_then_range);
+        fields.extend_from_slice(&self.hook);
+        for range_capture in &self.range_capture {
+            fields.extend_from_slice(range_capture);
+        }
+        fields.extend_from_slice(&[self.full_lion, self.limited_lion]);
+        fields
+    }
+
+    pub fn field_names() -> Vec<String> {
+        let directions = Direction::ALL.map(|d| format!("{d:?}").to_lowercase());
+        let mut names = Vec::with_capacity(Self::NUM_FIELDS);
+        for (field, len) in [("steps", 8), ("jumps", 4)] {
+            for direction in &directions {
+                names.extend((0..len).map(|n| format!("{field}[{direction}][{n}]")));
+            }
+        }
+        names.extend(directions.iter().map(|d| format!("range[{d}]")));
+        names.extend(["knight_forward", "knight_backward", "dove"].map(String::from));
+        for field in ["flying_jump", "flying_capture", "jump_then_range", "hook"] {
+            names.extend(directions.iter().map(|d| format!("{field}[{d}]")));
+        }
+        for rank in Rank::ALL.map(|r| format!("{r:?}").to_lowercase()) {
+            names.extend(
+                directions
+                    .iter()
+                    .map(|d| format!("range_capture[{rank}][{d}]")),
+            );
+        }
+        names.extend(["full_lion", "limited_lion"].map(String::from));
+        names
+    }
+
+    pub fn from_fields(fields: &[f64]) -> Self {
+        assert_eq!(fields.len(), Self::NUM_FIELDS);
+        let mut fields = fields.iter().copied();
+        let mut next = || fields.next().unwrap();
+        let mut mobility = Mobility::new();
+        for steps in &mut mobility.steps {
+            steps.fill_with(&mut next);
+        }
+        for jumps in &mut mobility.jumps {
+            jumps.fill_with(&mut next);
+        }
+        mobility.range.fill_with(&mut next);
+        mobility.knight_forward = next();
+        mobility.knight_backward = next();
+        mobility.dove = next();
+        mobility.flying_jump.fill_with(&mut next);
+        mobility.flying_capture.fill_with(&mut next);
+        mobility.jump_then_range.fill_with(&mut next);
+        mobility.hook.fill_with(&mut next);
+        for range_capture in &mut mobility.range_capture {
+            range_capture.fill_with(&mut next);
+        }
+        mobility.full_lion = next();
+        mobility.limited_lion = next();
+        mobility
+    }
+}
+
+impl Default for Mobility {
+    fn default() -> Self {
+        Mobility::new()
     }
 }
 
 impl Add for Simulation {
     type Output = Simulation;
 
-    fn add(mut self, rhs: Self) -> Self::Output {
-        for n in 0..8 {
-            self.orthogonal_steps[n] += rhs.orthogonal_steps[n];
-            self.diagonal_steps[n] += rhs.diagonal_steps[n];
-        }
-        for n in 0..4 {
-            self.orthogonal_jumps[n] += rhs.orthogonal_jumps[n];
-            self.diagonal_jumps[n] += rhs.diagonal_jumps[n];
-        }
-        self.dove += rhs.dove;
-        self.orthogonal_range += rhs.orthogonal_range;
-        self.diagonal_range += rhs.diagonal_range;
-        self.knight_jump += rhs.knight_jump;
-        self.orthogonal_flying_jump += rhs.orthogonal_flying_jump;
-        self.diagonal_flying_jump += rhs.diagonal_flying_jump;
-        self.orthogonal_flying_capture += rhs.orthogonal_flying_capture;
-        self.diagonal_flying_capture += rhs.diagonal_flying_capture;
-        self.orthogonal_jump_then_range += rhs.orthogonal_jump_then_range;
-        self.diagonal_jump_then_range += rhs.diagonal_jump_then_range;
-        self.orthogonal_hook += rhs.orthogonal_hook;
-        self.diagonal_hook += rhs.diagonal_hook;
-        self.full_lion += rhs.full_lion;
-        self.limited_lion += rhs.limited_lion;
-
-        self
+    fn add(self, rhs: Self) -> Self::Output {
+        let fields: Vec<f64> = self
+            .to_fields()
+            .iter()
+            .zip(rhs.to_fields())
+            .map(|(a, b)| a + b)
+            .collect();
+        Simulation::from_fields(&fields)
     }
 }
 
@@ -92,87 +201,53 @@ impl AddAssign for Simulation {
 impl Div<f64> for Simulation {
     type Output = Simulation;
 
-    fn div(mut self, divisor: f64) -> Self::Output {
-        for n in 0..8 {
-            self.orthogonal_steps[n] /= divisor;
-            self.diagonal_steps[n] /= divisor;
-        }
-        for n in 0..4 {
-            self.orthogonal_jumps[n] /= divisor;
-            self.diagonal_jumps[n] /= divisor;
-        }
-        self.dove /= divisor;
-        self.orthogonal_range /= divisor;
-        self.diagonal_range /= divisor;
-        self.knight_jump /= divisor;
-        self.orthogonal_flying_jump /= divisor;
-        self.diagonal_flying_jump /= divisor;
-        self.orthogonal_flying_capture /= divisor;
-        self.diagonal_flying_capture /= divisor;
-        self.orthogonal_jump_then_range /= divisor;
-        self.diagonal_jump_then_range /= divisor;
-        self.orthogonal_hook /= divisor;
-        self.diagonal_hook /= divisor;
-        self.full_lion /= divisor;
-        self.limited_lion /= divisor;
-
-        self
+    fn div(self, divisor: f64) -> Self::Output {
+        let fields: Vec<f64> = self.to_fields().iter().map(|x| x / divisor).collect();
+        Simulation::from_fields(&fields)
     }
 }
 
 impl Display for Simulation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Simulation Results:")?;
-        writeln!(f, "\tOrthogonal Steps:")?;
-        for n in 1..8 {
-            writeln!(f, "\t\t{n}: {}", self.orthogonal_steps[n])?;
-        }
-        writeln!(f, "\tDiagonal Steps:")?;
-        for n in 1..8 {
-            writeln!(f, "\t\t{n}: {}", self.diagonal_steps[n])?;
-        }
-        writeln!(f, "\tOrthogonal Range: {}", self.orthogonal_range)?;
-        writeln!(f, "\tDiagonal Range: {}", self.diagonal_range)?;
-        writeln!(f, "\tOrthogonal Jumps:")?;
-        for n in 2..4 {
-            writeln!(f, "\t\t{n}: {}", self.orthogonal_jumps[n])?;
+        writeln!(f, "\tQuiet Moves:")?;
+        write!(f, "{}", self.quiet)?;
+        writeln!(f, "\tCaptures:")?;
+        write!(f, "{}", self.captures)
+    }
+}
+
+impl Display for Mobility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn row(f: &mut std::fmt::Formatter<'_>, values: &[f64]) -> std::fmt::Result {
+            let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            writeln!(f, "{}", values.join(", "))
         }
-        writeln!(f, "\tDiagonal Jumps:")?;
-        for n in 2..4 {
-            writeln!(f, "\t\t{n}: {}", self.diagonal_jumps[n])?;
+
+        for (d, direction) in Direction::ALL.iter().enumerate() {
+            writeln!(f, "\t\t{direction:?}:")?;
+            write!(f, "\t\t\tSteps (1 to 7): ")?;
+            row(f, &self.steps[d][1..])?;
+            write!(f, "\t\t\tJumps (2 to 3): ")?;
+            row(f, &self.jumps[d][2..])?;
+            writeln!(f, "\t\t\tRange: {}", self.range[d])?;
+            writeln!(f, "\t\t\tJump then Range: {}", self.jump_then_range[d])?;
+            writeln!(f, "\t\t\tFlying Jump: {}", self.flying_jump[d])?;
+            writeln!(f, "\t\t\tFlying Capture: {}", self.flying_capture[d])?;
+            writeln!(f, "\t\t\tHook Moves: {}", self.hook[d])?;
+            write!(f, "\t\t\tRange Capture (Ordinary to Royal): ")?;
+            let range_capture: Vec<f64> = self.range_capture.iter().map(|r| r[d]).collect();
+            row(f, &range_capture)?;
         }
-        writeln!(f, "\tKnight-Style Jump: {}", self.knight_jump)?;
-        writeln!(
-            f,
-            "\tOrthogonal Jump then Range: {}",
-            self.orthogonal_jump_then_range
-        )?;
-        writeln!(
-            f,
-            "\tDiagonal Jump then Range: {}",
-            self.diagonal_jump_then_range
-        )?;
-        writeln!(
-            f,
-            "\tOrthogonal Flying Jump: {}",
-            self.orthogonal_flying_jump
-        )?;
-        writeln!(f, "\tDiagonal Flying Jump: {}", self.diagonal_flying_jump)?;
-        writeln!(
-            f,
-            "\tOrthogonal Flying Capture: {}",
-            self.orthogonal_flying_capture
-        )?;
+        writeln!(f, "\t\tKnight-Style Jump Forward: {}", self.knight_forward)?;
         writeln!(
             f,
-            "\tDiagonal Flying Capture: {}",
-            self.diagonal_flying_capture
+            "\t\tKnight-Style Jump Backward: {}",
+            self.knight_backward
         )?;
-        writeln!(f, "\tOrthogonal Hook Moves: {}", self.orthogonal_hook)?;
-        writeln!(f, "\tDiagonal Hook Moves: {}", self.diagonal_hook)?;
-        writeln!(f, "\tDove Moves: {}", self.dove)?;
-        writeln!(f, "\tFull lion: {}", self.full_lion)?;
-        writeln!(f, "\tLimited lion: {}", self.limited_lion)?;
+        writeln!(f, "\t\tDove Moves: {}", self.dove)?;
+        writeln!(f, "\t\tFull lion: {}", self.full_lion)?;
+        writeln!(f, "\t\tLimited lion: {}", self.limited_lion)?;
 
         Ok(())
     }
@@ -0,0 +1,318 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign};
+
+use super::Simulation;
+
+const Z_95: f64 = 1.959_963_984_540_054;
+
+// Running count, mean and co-moment matrix of every `Simulation` field, updated
+// with Welford's algorithm and merged with Chan et al.'s pairwise formula. The
+// full co-moment matrix is kept (rather than just the variances) so that the
+// uncertainty of any linear combination of fields, such as a face value, can be
+// recovered afterwards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationStatistics {
+    count: u64,
+    #[serde(deserialize_with = "fields")]
+    mean: Vec<f64>,
+    #[serde(with = "packed")]
+    comoment: Vec<f64>,
+}
+
+impl SimulationStatistics {
+    pub fn new() -> Self {
+        SimulationStatistics {
+            count: 0,
+            mean: vec![0.0; Simulation::NUM_FIELDS],
+            comoment: vec![0.0; Simulation::NUM_FIELDS * Simulation::NUM_FIELDS],
+        }
+    }
+
+    pub fn push(&mut self, sim: &Simulation) {
+        let n = Simulation::NUM_FIELDS;
+        let x = sim.to_fields();
+
+        self.count += 1;
+        let count = self.count as f64;
+        let delta: Vec<f64> = x.iter().zip(&self.mean).map(|(x, m)| x - m).collect();
+        for (mean, delta) in self.mean.iter_mut().zip(&delta) {
+            *mean += delta / count;
+        }
+        let residual: Vec<f64> = x.iter().zip(&self.mean).map(|(x, m)| x - m).collect();
+        // Only the upper triangle is updated and then mirrored, keeping the
+        // matrix exactly symmetric so that saving half of it loses nothing.
+        for (i, delta) in delta.iter().enumerate() {
+            for (j, residual) in residual.iter().enumerate().skip(i) {
+                let comoment = self.comoment[i * n + j] + delta * residual;
+                self.comoment[i * n + j] = comoment;
+                self.comoment[j * n + i] = comoment;
+            }
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> Simulation {
+        Simulation::from_fields(&self.mean)
+    }
+
+    pub fn variance(&self) -> Simulation {
+        let n = Simulation::NUM_FIELDS;
+        let variances: Vec<f64> = (0..n).map(|i| self.covariance(i, i)).collect();
+        Simulation::from_fields(&variances)
+    }
+
+    pub fn standard_error(&self) -> Simulation {
+        let n = Simulation::NUM_FIELDS;
+        let errors: Vec<f64> = (0..n)
+            .map(|i| self.standard_error_of(self.covariance(i, i)))
+            .collect();
+        Simulation::from_fields(&errors)
+    }
+
+    pub fn confidence_interval(&self) -> (Simulation, Simulation) {
+        let errors = self.standard_error().to_fields();
+        let lower: Vec<f64> = self
+            .mean
+            .iter()
+            .zip(&errors)
+            .map(|(m, e)| m - Z_95 * e)
+            .collect();
+        let upper: Vec<f64> = self
+            .mean
+            .iter()
+            .zip(&errors)
+            .map(|(m, e)| m + Z_95 * e)
+            .collect();
+        (
+            Simulation::from_fields(&lower),
+            Simulation::from_fields(&upper),
+        )
+    }
+
+    // `value` must be linear in the fields of the simulation, which holds for
+    // `Simulation::pawn` and for `assign_value_to_face`.
+    pub fn estimate(&self, value: impl Fn(&Simulation) -> f64) -> Estimate {
+        let w = weights(&value);
+        Estimate {
+            mean: dot(&w, &self.mean),
+            standard_error: self.standard_error_of(self.covariance_of(&w, &w)),
+        }
+    }
+
+    // Estimates `value / reference` with the delta method, accounting for the
+    // correlation between the two within a trial.
+    pub fn estimate_ratio(
+        &self,
+        value: impl Fn(&Simulation) -> f64,
+        reference: impl Fn(&Simulation) -> f64,
+    ) -> Estimate {
+        let a = weights(&value);
+        let b = weights(&reference);
+        let numerator = dot(&a, &self.mean);
+        let denominator = dot(&b, &self.mean);
+        let ratio = numerator / denominator;
+        let variance = (self.covariance_of(&a, &a) - 2.0 * ratio * self.covariance_of(&a, &b)
+            + ratio * ratio * self.covariance_of(&b, &b))
+            / (denominator * denominator);
+        Estimate {
+            mean: ratio,
+            standard_error: self.standard_error_of(variance),
+        }
+    }
+
+    // Rounding can leave the variance of a value with no spread, such as a face
+    // that is an exact multiple of the pawn, just below zero. A NaN variance,
+    // as from a reference valued at zero, stays NaN.
+    fn standard_error_of(&self, variance: f64) -> f64 {
+        if self.count < 2 {
+            return f64::NAN;
+        }
+        let variance = if variance < 0.0 { 0.0 } else { variance };
+        (variance / self.count as f64).sqrt()
+    }
+
+    fn covariance(&self, i: usize, j: usize) -> f64 {
+        if self.count < 2 {
+            return f64::NAN;
+        }
+        self.comoment[i * Simulation::NUM_FIELDS + j] / (self.count - 1) as f64
+    }
+
+    fn covariance_of(&self, a: &[f64], b: &[f64]) -> f64 {
+        a.iter()
+            .enumerate()
+            .map(|(i, a)| {
+                b.iter()
+                    .enumerate()
+                    .map(|(j, b)| a * b * self.covariance(i, j))
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+}
+
+impl Default for SimulationStatistics {
+    fn default() -> Self {
+        SimulationStatistics::new()
+    }
+}
+
+impl Add for SimulationStatistics {
+    type Output = SimulationStatistics;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.count == 0 {
+            return rhs;
+        }
+        if rhs.count == 0 {
+            return self;
+        }
+
+        let n = Simulation::NUM_FIELDS;
+        let count = self.count + rhs.count;
+        let (na, nb, nt) = (self.count as f64, rhs.count as f64, count as f64);
+        let delta: Vec<f64> = rhs
+            .mean
+            .iter()
+            .zip(&self.mean)
+            .map(|(b, a)| b - a)
+            .collect();
+
+        let mean = (0..n).map(|i| self.mean[i] + delta[i] * nb / nt).collect();
+        let mut comoment = vec![0.0; n * n];
+        for i in 0..n {
+            for j in 0..n {
+                comoment[i * n + j] = self.comoment[i * n + j]
+                    + rhs.comoment[i * n + j]
+                    + delta[i] * delta[j] * na * nb / nt;
+            }
+        }
+
+        SimulationStatistics {
+            count,
+            mean,
+            comoment,
+        }
+    }
+}
+
+impl AddAssign for SimulationStatistics {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) + rhs;
+    }
+}
+
+// The co-moment matrix is symmetric, so only its upper triangle is saved, as
+// base64 of little-endian floats. Records written with the full matrix as a
+// JSON array still load.
+mod packed {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Simulation;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Saved {
+        Packed(String),
+        Full(Vec<f64>),
+    }
+
+    pub fn serialize<S: Serializer>(comoment: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+        let n = Simulation::NUM_FIELDS;
+        let mut bytes = Vec::with_capacity(n * (n + 1) / 2 * 8);
+        for i in 0..n {
+            for x in &comoment[i * n + i..(i + 1) * n] {
+                bytes.extend_from_slice(&x.to_le_bytes());
+            }
+        }
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+        let n = Simulation::NUM_FIELDS;
+        let packed = match Saved::deserialize(deserializer)? {
+            Saved::Full(comoment) if comoment.len() == n * n => return Ok(comoment),
+            Saved::Full(_) => {
+                return Err(D::Error::custom("the co-moment matrix has the wrong size"))
+            }
+            Saved::Packed(packed) => STANDARD.decode(packed).map_err(D::Error::custom)?,
+        };
+        if packed.len() != n * (n + 1) / 2 * 8 {
+            return Err(D::Error::custom("the co-moment matrix has the wrong size"));
+        }
+
+        let mut values = packed
+            .chunks_exact(8)
+            .map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap()));
+        let mut comoment = vec![0.0; n * n];
+        for i in 0..n {
+            for j in i..n {
+                let x = values.next().unwrap();
+                comoment[i * n + j] = x;
+                comoment[j * n + i] = x;
+            }
+        }
+        Ok(comoment)
+    }
+}
+
+// Saved statistics from another field layout cannot be used, so they are
+// rejected on loading rather than when they are first used.
+fn fields<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+    let fields = Vec::<f64>::deserialize(deserializer)?;
+    if fields.len() != Simulation::NUM_FIELDS {
+        return Err(D::Error::custom(format!(
+            "expected {} fields, found {}",
+            Simulation::NUM_FIELDS,
+            fields.len()
+        )));
+    }
+    Ok(fields)
+}
+
+fn weights(value: impl Fn(&Simulation) -> f64) -> Vec<f64> {
+    let n = Simulation::NUM_FIELDS;
+    let mut basis = vec![0.0; n];
+    (0..n)
+        .map(|i| {
+            basis[i] = 1.0;
+            let weight = value(&Simulation::from_fields(&basis));
+            basis[i] = 0.0;
+            weight
+        })
+        .collect()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Estimate {
+    pub mean: f64,
+    pub standard_error: f64,
+}
+
+impl Estimate {
+    pub fn half_width(&self) -> f64 {
+        Z_95 * self.standard_error
+    }
+
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        (self.mean - self.half_width(), self.mean + self.half_width())
+    }
+}
+
+impl Display for Estimate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2} ± {:.2}", self.mean, self.half_width())
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use crate::face::{Face, Move, Rank};
+use crate::simulate::Mobility;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    UnknownPromotion { face: String, promotion: String },
+    DuplicateName(String),
+    DuplicateKanji { kanji: String, faces: Vec<String> },
+    StepOutOfRange { face: String, m: Move },
+    JumpOutOfRange { face: String, m: Move },
+    DuplicateMoves { face: String, moves: Vec<Move> },
+    UnrankedRangeCapture { face: String, m: Move },
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::UnknownPromotion { face, promotion } => {
+                write!(f, "{face}: promotes to unknown face '{promotion}'")
+            }
+            Problem::DuplicateName(name) => write!(f, "{name}: name is used by several faces"),
+            Problem::DuplicateKanji { kanji, faces } => {
+                write!(f, "{kanji}: kanji shared by {}", faces.join(", "))
+            }
+            Problem::StepOutOfRange { face, m } => write!(
+                f,
+                "{face}: {m:?} steps further than the {} squares simulated",
+                Mobility::default().steps[0].len() - 1
+            ),
+            Problem::JumpOutOfRange { face, m } => write!(
+                f,
+                "{face}: {m:?} jumps further than the {} squares simulated",
+                Mobility::default().jumps[0].len() - 1
+            ),
+            Problem::DuplicateMoves { face, moves } => {
+                write!(f, "{face}: several moves in one direction: {moves:?}")
+            }
+            Problem::UnrankedRangeCapture { face, m } => {
+                write!(f, "{face}: {m:?} on an ordinary face is only a range move")
+            }
+        }
+    }
+}
+
+// Checks a face table for mistakes that would otherwise surface as lookups
+// finding the wrong face or as out-of-bounds panics while assigning values.
+pub fn validate_faces(faces: &[Face]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let names: HashSet<&str> = faces.iter().map(|face| face.name.as_str()).collect();
+
+    let mut seen = HashSet::new();
+    for face in faces {
+        if !seen.insert(&face.name) {
+            problems.push(Problem::DuplicateName(face.name.clone()));
+        }
+    }
+
+    // Faces that only differ by a left or right prefix share the rest of
+    // their kanji.
+    let mut by_kanji: HashMap<String, Vec<String>> = HashMap::new();
+    for face in faces {
+        let kanji = format!(
+            "{}{}",
+            face.additional_kanji.as_deref().unwrap_or_default(),
+            face.kanji
+        );
+        by_kanji.entry(kanji).or_default().push(face.name.clone());
+    }
+    let mut shared: Vec<_> = by_kanji
+        .into_iter()
+        .filter(|(_, faces)| faces.len() > 1)
+        .collect();
+    shared.sort_by(|(_, a), (_, b)| a.cmp(b));
+    for (kanji, faces) in shared {
+        problems.push(Problem::DuplicateKanji { kanji, faces });
+    }
+
+    let simulated = Mobility::default();
+    for face in faces {
+        if let Some(promotion) = &face.promotion {
+            if !names.contains(promotion.as_str()) {
+                problems.push(Problem::UnknownPromotion {
+                    face: face.name.clone(),
+                    promotion: promotion.clone(),
+                });
+            }
+        }
+
+        for m in &face.moves {
+            let problem = match m {
+                Move::Step(n, _) if *n as usize >= simulated.steps[0].len() => {
+                    Problem::StepOutOfRange {
+                        face: face.name.clone(),
+                        m: m.clone(),
+                    }
+                }
+                Move::Jump(n, _) | Move::JumpOrRange(n, _)
+                    if *n as usize >= simulated.jumps[0].len() =>
+                {
+                    Problem::JumpOutOfRange {
+                        face: face.name.clone(),
+                        m: m.clone(),
+                    }
+                }
+                Move::RangeCapture(_) if face.rank == Rank::Ordinary => {
+                    Problem::UnrankedRangeCapture {
+                        face: face.name.clone(),
+                        m: m.clone(),
+                    }
+                }
+                _ => continue,
+            };
+            problems.push(problem);
+        }
+
+        let mut by_direction: Vec<Vec<Move>> = Vec::new();
+        for (i, m) in face.moves.iter().enumerate() {
+            let same = |other: &Move| match (m.direction(), other.direction()) {
+                (Some(a), Some(b)) => a == b,
+                _ => m == other,
+            };
+            if face.moves[..i].iter().any(same) {
+                continue;
+            }
+            let moves: Vec<Move> = face.moves[i..]
+                .iter()
+                .filter(|o| same(o))
+                .cloned()
+                .collect();
+            if moves.len() > 1 {
+                by_direction.push(moves);
+            }
+        }
+        for moves in by_direction {
+            problems.push(Problem::DuplicateMoves {
+                face: face.name.clone(),
+                moves,
+            });
+        }
+    }
+
+    problems
+}
@@ -0,0 +1,209 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::export::FormatError;
+use crate::face::{faces, Face, Move};
+use crate::simulate::{Board, BoardError};
+
+// A shogi variant: the faces used in it, the board it is played on and, where
+// it is known, the array the armies start from.
+#[derive(Debug, Clone)]
+pub struct Variant {
+    pub name: String,
+    pub board: Board,
+    pub faces: Vec<Face>,
+    pub setup: Option<StartingArray>,
+}
+
+// A side's pieces at the start of a game as (file, rank, face), seen from that
+// side: files from its left and ranks from its back rank. The opponent's army
+// is the same array turned round.
+#[derive(Debug, Clone)]
+pub struct StartingArray {
+    pub pieces: Vec<(usize, usize, Face)>,
+}
+
+// A variant file names its faces from the taikyoku table rather than
+// repeating them. Each entry gives the face's promotion in the variant, which
+// often differs from taikyoku's, and may replace its moves where the variant's
+// piece moves differently, either listing the moves or naming an override
+// shared with other variants.
+#[derive(Deserialize)]
+struct VariantFile {
+    name: String,
+    board: String,
+    setup: Option<Vec<Vec<String>>>,
+    faces: Vec<VariantFace>,
+}
+
+#[derive(Deserialize)]
+struct VariantFace {
+    name: String,
+    promotion: Option<String>,
+    moves: Option<VariantMoves>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VariantMoves {
+    Shared(String),
+    Listed(Vec<Move>),
+}
+
+const CHU: &str = include_str!("../data/chu.toml");
+const DAI: &str = include_str!("../data/dai.toml");
+const TENJIKU: &str = include_str!("../data/tenjiku.toml");
+const OVERRIDES: &str = include_str!("../data/overrides.toml");
+
+// The built-in variants, smallest board first. Taikyoku is the face table
+// itself. Tai shogi is not built in yet: its 93 kinds of piece still need to
+// be checked against a source, and can be supplied through `parse_variant`.
+pub fn variants() -> Vec<Variant> {
+    let table = faces();
+    let mut variants: Vec<Variant> = [CHU, DAI, TENJIKU]
+        .iter()
+        .map(|toml| parse_variant(toml, &table).expect("a built-in variant is invalid"))
+        .collect();
+    variants.push(Variant {
+        name: "taikyoku".to_string(),
+        board: Board::TAIKYOKU,
+        faces: table,
+        setup: None,
+    });
+    variants
+}
+
+pub fn variant(name: &str) -> Option<Variant> {
+    variants()
+        .into_iter()
+        .find(|variant| variant.name.eq_ignore_ascii_case(name))
+}
+
+// The starting array of the built-in variant played on `board`, if it has one.
+pub fn starting_array(board: Board) -> Option<StartingArray> {
+    variants()
+        .into_iter()
+        .find(|variant| variant.board == board)?
+        .setup
+}
+
+// Reads a variant in the format of `data/chu.toml`, taking its faces from
+// `table` and shared moves from `data/overrides.toml`.
+pub fn parse_variant(toml: &str, table: &[Face]) -> Result<Variant, VariantError> {
+    let file: VariantFile = toml::from_str(toml).map_err(FormatError::from)?;
+    let overrides: HashMap<String, Vec<Move>> =
+        toml::from_str(OVERRIDES).expect("the built-in overrides are invalid");
+    let board: Board = file.board.parse()?;
+    let faces: Vec<Face> = file
+        .faces
+        .into_iter()
+        .map(|entry| {
+            let face = table
+                .iter()
+                .find(|face| face.name == entry.name)
+                .ok_or_else(|| VariantError::UnknownFace(entry.name.clone()))?;
+            let moves = match entry.moves {
+                None => face.moves.clone(),
+                Some(VariantMoves::Listed(moves)) => moves,
+                Some(VariantMoves::Shared(name)) => overrides
+                    .get(&name)
+                    .cloned()
+                    .ok_or(VariantError::UnknownOverride(name))?,
+            };
+            Ok(Face {
+                promotion: entry.promotion,
+                moves,
+                ..face.clone()
+            })
+        })
+        .collect::<Result<_, VariantError>>()?;
+    let setup = match file.setup {
+        Some(ranks) => Some(starting_array_of(&ranks, board, &faces)?),
+        None => None,
+    };
+
+    Ok(Variant {
+        name: file.name,
+        board,
+        faces,
+        setup,
+    })
+}
+
+// The array must fit within its side's half of the board, leaving at least one
+// empty rank between the armies.
+fn starting_array_of(
+    ranks: &[Vec<String>],
+    board: Board,
+    faces: &[Face],
+) -> Result<StartingArray, VariantError> {
+    if ranks.len() > (board.height() - 1) / 2 {
+        return Err(VariantError::Setup(format!(
+            "the starting array has {} ranks, more than half the board",
+            ranks.len()
+        )));
+    }
+    let mut pieces = Vec::new();
+    for (rank, names) in ranks.iter().enumerate() {
+        if names.len() != board.width() {
+            return Err(VariantError::Setup(format!(
+                "rank {} of the starting array has {} squares instead of {}",
+                rank + 1,
+                names.len(),
+                board.width()
+            )));
+        }
+        for (file, name) in names
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| !name.is_empty())
+        {
+            let face = faces
+                .iter()
+                .find(|face| face.name == *name)
+                .ok_or_else(|| VariantError::UnknownFace(name.clone()))?;
+            pieces.push((file, rank, face.clone()));
+        }
+    }
+    Ok(StartingArray { pieces })
+}
+
+#[derive(Debug)]
+pub enum VariantError {
+    Format(FormatError),
+    Board(BoardError),
+    UnknownFace(String),
+    UnknownOverride(String),
+    Setup(String),
+}
+
+impl Display for VariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VariantError::Format(e) => write!(f, "{e}"),
+            VariantError::Board(e) => write!(f, "{e}"),
+            VariantError::UnknownFace(name) => {
+                write!(f, "'{name}' is not in the face table")
+            }
+            VariantError::UnknownOverride(name) => {
+                write!(f, "'{name}' is not a shared override")
+            }
+            VariantError::Setup(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for VariantError {}
+
+impl From<FormatError> for VariantError {
+    fn from(e: FormatError) -> Self {
+        VariantError::Format(e)
+    }
+}
+
+impl From<BoardError> for VariantError {
+    fn from(e: BoardError) -> Self {
+        VariantError::Board(e)
+    }
+}
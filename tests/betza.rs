@@ -0,0 +1,54 @@
+use shogi_piece_values::{faces, parse_betza, to_betza, Move};
+
+// The moves as Betza spells them: adjacent leaps are steps and a jump or range
+// move is a leap together with a slide.
+fn spelled(moves: &[Move]) -> Vec<Move> {
+    let mut spelled = Vec::new();
+    for m in moves {
+        let atoms = match m {
+            Move::Jump(1, direction) => vec![Move::Step(1, direction.clone())],
+            Move::JumpOrRange(1, direction) => {
+                vec![
+                    Move::Step(1, direction.clone()),
+                    Move::Range(direction.clone()),
+                ]
+            }
+            Move::JumpOrRange(n, direction) => {
+                vec![
+                    Move::Jump(*n, direction.clone()),
+                    Move::Range(direction.clone()),
+                ]
+            }
+            m => vec![m.clone()],
+        };
+        for atom in atoms {
+            if !spelled.contains(&atom) {
+                spelled.push(atom);
+            }
+        }
+    }
+    spelled
+}
+
+#[test]
+fn every_face_round_trips_through_betza() {
+    for face in faces() {
+        let betza = to_betza(&face.moves);
+        let expressible: Vec<Move> = face
+            .moves
+            .iter()
+            .filter(|m| !betza.inexpressible.contains(m))
+            .cloned()
+            .collect();
+        let parsed = parse_betza(&betza.notation)
+            .unwrap_or_else(|e| panic!("{}: cannot parse {}: {e}", face.name, betza.notation));
+
+        let (expected, parsed) = (spelled(&expressible), spelled(&parsed));
+        assert!(
+            expected.len() == parsed.len() && expected.iter().all(|m| parsed.contains(m)),
+            "{}: {} parses as {parsed:?}, not {expected:?}",
+            face.name,
+            betza.notation
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use shogi_piece_values::{faces, Catalogue, LookupError};
+
+fn find(query: &str) -> Result<String, LookupError> {
+    let catalogue = Catalogue::new(&faces());
+    catalogue.find(query).map(|face| face.name.clone())
+}
+
+#[test]
+fn faces_are_found_by_name_kanji_and_romaji() {
+    assert_eq!(find("King").unwrap(), "King");
+    assert_eq!(find("玉将").unwrap(), "King");
+    assert_eq!(find("gyokushō").unwrap(), "King");
+    assert_eq!(find("gyokusho").unwrap(), "King");
+    assert_eq!(find("free king").unwrap(), "Free King");
+    assert_eq!(find("FREE-KING").unwrap(), "Free King");
+}
+
+#[test]
+fn unknown_and_ambiguous_queries_are_errors() {
+    assert!(matches!(
+        find("Nonexistent General"),
+        Err(LookupError::Unknown(_))
+    ));
+    match find("山鷲") {
+        Err(LookupError::Ambiguous { names, .. }) => {
+            assert!(names.contains(&"Left Mountain Eagle".to_string()));
+            assert!(names.contains(&"Right Mountain Eagle".to_string()));
+        }
+        other => panic!("{other:?}"),
+    }
+    assert!(matches!(find("kyosha"), Err(LookupError::Ambiguous { .. })));
+}
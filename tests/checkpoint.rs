@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use shogi_piece_values::{
+    resume_from_checkpoint, simulate_n_par_statistics, simulate_with_checkpoints, Board, Uniform,
+};
+
+fn checkpoint_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "shogi-piece-values-{}-{name}.json",
+        std::process::id()
+    ))
+}
+
+fn resumed(stopped_at: usize, trials: usize, name: &str) -> Vec<f64> {
+    let board = Board::preset("chu").unwrap();
+    let path = checkpoint_path(name);
+    simulate_with_checkpoints(board, &Uniform, stopped_at, 7, &path, 256).unwrap();
+    let statistics = resume_from_checkpoint(board, &Uniform, trials, &path, 256).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(statistics.count(), trials as u64);
+    statistics.mean().to_fields()
+}
+
+fn fresh(trials: usize) -> Vec<f64> {
+    let board = Board::preset("chu").unwrap();
+    simulate_n_par_statistics(board, &Uniform, trials, 7)
+        .mean()
+        .to_fields()
+}
+
+#[test]
+fn runs_resumed_from_a_chunk_boundary_match_fresh_runs() {
+    assert_eq!(resumed(512, 600, "boundary"), fresh(600));
+}
+
+#[test]
+fn runs_resumed_between_chunk_boundaries_match_fresh_runs() {
+    for (resumed, fresh) in resumed(300, 600, "between").into_iter().zip(fresh(600)) {
+        assert!(
+            (resumed - fresh).abs() <= 1e-12 * fresh.abs().max(1.0),
+            "{resumed} != {fresh}"
+        );
+    }
+}
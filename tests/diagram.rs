@@ -0,0 +1,63 @@
+use shogi_piece_values::{faces, parse_betza, Catalogue, Charset, Diagram};
+
+fn draw(name: &str) -> String {
+    let catalogue = Catalogue::new(&faces());
+    let face = catalogue.lookup(name)[0];
+    let diagram = Diagram::new(&face.moves);
+    diagram.render(Charset::Ascii) + &diagram.legend(Charset::Ascii)
+}
+
+#[test]
+fn gold_general() {
+    assert_eq!(
+        draw("Gold General"),
+        "\
+o o o
+o @ o
+. o .
+o step"
+    );
+}
+
+#[test]
+fn lance_and_knight() {
+    assert_eq!(
+        draw("Lance"),
+        "\
+. . . | . . .
+. . . | . . .
+. . . | . . .
+. . . @ . . .
+. . . . . . .
+. . . . . . .
+. . . . . . .
+| range"
+    );
+    assert_eq!(
+        draw("Knight"),
+        "\
+. n . n .
+. . . . .
+. . @ . .
+. . . . .
+. . . . .
+n knight"
+    );
+}
+
+#[test]
+fn jumps_are_drawn_over_ranges() {
+    let moves = parse_betza("fRfD").unwrap();
+    assert_eq!(
+        Diagram::new(&moves).render(Charset::Ascii),
+        "\
+. . . | . . .
+. . . x . . .
+. . . | . . .
+. . . @ . . .
+. . . . . . .
+. . . . . . .
+. . . . . . .
+"
+    );
+}
@@ -0,0 +1,47 @@
+use shogi_piece_values::{
+    assign_value_to_face, faces, simulate_n_par_statistics, Board, Direction, Estimate, Face, Move,
+    OccupancyModel, Setup, Territorial, Uniform,
+};
+
+fn range(direction: Direction) -> Face {
+    Face {
+        moves: vec![Move::Range(direction)],
+        ..faces()[0].clone()
+    }
+}
+
+// How much more ranging north is worth than ranging south. Single steps would
+// not do: mobility is counted from every square, so a one-step move meets each
+// piece once whichever way it points.
+fn forward_advantage(occupancy: &dyn OccupancyModel) -> Estimate {
+    let board = Board::preset("chu").unwrap();
+    let statistics = simulate_n_par_statistics(board, occupancy, 512, 9);
+    let (north, south) = (range(Direction::North), range(Direction::South));
+    statistics.estimate(|sim| assign_value_to_face(sim, &north) - assign_value_to_face(sim, &south))
+}
+
+#[test]
+fn forward_and_backward_ranges_differ_when_the_armies_face_each_other() {
+    for occupancy in [
+        &Setup {
+            max_removed: 0.5,
+            array: None,
+        } as &dyn OccupancyModel,
+        &Territorial,
+    ] {
+        let advantage = forward_advantage(occupancy);
+        assert!(
+            advantage.mean > 4.0 * advantage.standard_error,
+            "{occupancy}: {advantage}"
+        );
+    }
+}
+
+#[test]
+fn forward_and_backward_ranges_agree_when_pieces_are_scattered() {
+    let advantage = forward_advantage(&Uniform);
+    assert!(
+        advantage.mean.abs() < 4.0 * advantage.standard_error,
+        "{advantage}"
+    );
+}
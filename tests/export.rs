@@ -0,0 +1,25 @@
+use shogi_piece_values::{simulate_n_seeded, Board, Simulation, Uniform};
+
+fn simulation() -> Simulation {
+    simulate_n_seeded(Board::preset("chu").unwrap(), &Uniform, 16, 3)
+}
+
+#[test]
+fn csv_round_trips() {
+    let simulation = simulation();
+    let csv = simulation.to_csv();
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("field,value"));
+    let names: Vec<&str> = lines.map(|row| row.split(',').next().unwrap()).collect();
+    assert_eq!(names, Simulation::field_names());
+
+    let parsed = Simulation::from_csv(&csv).unwrap();
+    assert_eq!(parsed.to_fields(), simulation.to_fields());
+}
+
+#[test]
+fn json_round_trips() {
+    let simulation = simulation();
+    let parsed = Simulation::from_json(&simulation.to_json()).unwrap();
+    assert_eq!(parsed.to_fields(), simulation.to_fields());
+}
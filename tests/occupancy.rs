@@ -0,0 +1,130 @@
+use shogi_piece_values::{
+    faces, occupancy_model, starting_array, trial_rng, variant, Board, Grid, OccupancyModel, Rank,
+    RankMix, Ranked, Replay, Setup, Square, Uniform,
+};
+
+#[test]
+fn replay_copies_a_position() {
+    let board = Board::new(3, 3).unwrap();
+    let replay = Replay::parse("replay:test", "# a comment\nF . O\n...\n.F.\n", board).unwrap();
+    let mut grid = Grid::new(board);
+    replay.populate(&mut grid, &mut trial_rng(1, 0));
+    assert_eq!(grid.get(0, 0), Square::Friendly(Rank::Ordinary));
+    assert_eq!(grid.get(2, 0), Square::Opponent(Rank::Ordinary));
+    assert_eq!(grid.get(1, 2), Square::Friendly(Rank::Ordinary));
+    assert_eq!(grid.get(1, 1), Square::Empty);
+
+    assert!(Replay::parse("replay:test", "F.O\n...\n", board).is_err());
+    assert!(Replay::parse("replay:test", "F.X\n...\n...\n", board).is_err());
+}
+
+#[test]
+fn setup_keeps_each_army_in_its_camp() {
+    let board = Board::preset("chu").unwrap();
+    let mut grid = Grid::new(board);
+    let setup = Setup {
+        max_removed: 0.5,
+        array: None,
+    };
+    setup.populate(&mut grid, &mut trial_rng(1, 0));
+    for x in 0..board.width() {
+        for y in 0..board.height() {
+            match grid.get(x, y) {
+                Square::Friendly(_) => assert!(y >= 8),
+                Square::Opponent(_) => assert!(y < 4),
+                Square::Empty => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn setup_without_captures_places_the_starting_array() {
+    let chu = variant("chu").unwrap();
+    let array = chu.setup.clone().unwrap();
+    assert_eq!(array.pieces.len(), 46);
+    let (width, height) = (chu.board.width(), chu.board.height());
+    let mut expected = vec![vec![Square::Empty; width]; height];
+    for (file, rank, face) in &array.pieces {
+        expected[height - 1 - rank][*file] = Square::Friendly(face.rank);
+        expected[*rank][width - 1 - file] = Square::Opponent(face.rank);
+    }
+
+    let model = Ranked {
+        model: occupancy_model("setup", chu.board).unwrap(),
+        ranks: RankMix::of(&chu.faces),
+    };
+    let mut grid = Grid::new(chu.board);
+    model.populate(&mut grid, &mut trial_rng(1, 0));
+    for (y, rank) in expected.iter().enumerate() {
+        for (x, square) in rank.iter().enumerate() {
+            assert_eq!(grid.get(x, y), *square, "square ({x}, {y})");
+        }
+    }
+    assert_eq!(grid.get(6, 11), Square::Friendly(Rank::Royal));
+    assert_eq!(grid.get(5, 0), Square::Opponent(Rank::Royal));
+    assert!(starting_array(Board::TAIKYOKU).is_none());
+}
+
+#[test]
+fn models_are_named_as_they_are_parsed() {
+    for name in [
+        "uniform",
+        "setup",
+        "setup:0.25",
+        "density:0.5",
+        "phase",
+        "phase:endgame",
+        "territorial",
+    ] {
+        let model = occupancy_model(name, Board::TAIKYOKU).unwrap();
+        assert_eq!(model.to_string(), name);
+    }
+    assert!(occupancy_model("density:2", Board::TAIKYOKU).is_err());
+}
+
+#[test]
+fn placed_pieces_keep_their_rank() {
+    let board = Board::TAIKYOKU;
+    let ranked = |model: &dyn OccupancyModel| {
+        let mut grid = Grid::new(board);
+        model.populate(&mut grid, &mut trial_rng(1, 0));
+        (0..board.width())
+            .flat_map(|x| (0..board.height()).map(move |y| (x, y)))
+            .filter(|&(x, y)| grid.get(x, y).rank() != Rank::Ordinary)
+            .count()
+    };
+    assert_eq!(ranked(&Uniform), 0);
+    let model = Ranked {
+        model: Box::new(Uniform),
+        ranks: RankMix::of(&faces()),
+    };
+    assert!(ranked(&model) > 0);
+
+    let mut grid = Grid::new(board);
+
+    grid.set(Square::Opponent(Rank::GreatGeneral), 0, 0);
+    assert_eq!(grid.get(0, 0), Square::Opponent(Rank::GreatGeneral));
+    grid.set(Square::Friendly(Rank::Ordinary), 0, 0);
+    assert_eq!(grid.get(0, 0), Square::Friendly(Rank::Ordinary));
+}
+
+#[test]
+fn ranked_models_draw_ranks_from_the_face_table() {
+    let chu = variant("chu").unwrap();
+    let ranks = RankMix::of(&chu.faces);
+    let model = Ranked {
+        model: Box::new(Uniform),
+        ranks,
+    };
+    assert_eq!(model.to_string(), format!("uniform, ranked {ranks}"));
+
+    let mut grid = Grid::new(chu.board);
+    model.populate(&mut grid, &mut trial_rng(1, 0));
+    for x in 0..chu.board.width() {
+        for y in 0..chu.board.height() {
+            let rank = grid.get(x, y).rank();
+            assert!(matches!(rank, Rank::Ordinary | Rank::Royal), "{rank:?}");
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use shogi_piece_values::{
+    assign_value_to_face, faces, simulate_n_seeded, Board, Face, PromotionGraph, Simulation,
+    Uniform,
+};
+
+fn simulation() -> Simulation {
+    simulate_n_seeded(Board::preset("chu").unwrap(), &Uniform, 16, 2)
+}
+
+fn face(name: &str) -> Face {
+    faces().into_iter().find(|face| face.name == name).unwrap()
+}
+
+#[test]
+fn faces_without_a_promotion_keep_their_own_value() {
+    let (simulation, graph) = (simulation(), PromotionGraph::new(&faces()).unwrap());
+    let king = face("King");
+    assert!(graph.promotion(&king).is_none());
+    for probability in [0.0, 0.5, 1.0] {
+        assert_eq!(
+            graph.blended_value(&simulation, &king, probability),
+            assign_value_to_face(&simulation, &king)
+        );
+    }
+}
+
+#[test]
+fn certain_promotion_is_worth_the_promoted_face() {
+    let (simulation, graph) = (simulation(), PromotionGraph::new(&faces()).unwrap());
+    let pawn = face("Pawn");
+    let promoted = graph.promotion(&pawn).unwrap();
+    assert_eq!(
+        graph.blended_value(&simulation, &pawn, 1.0),
+        assign_value_to_face(&simulation, promoted)
+    );
+    assert_eq!(
+        graph.blended_value(&simulation, &pawn, 0.0),
+        assign_value_to_face(&simulation, &pawn)
+    );
+}
+
+#[test]
+fn unknown_promotions_are_reported() {
+    let mut faces = faces();
+    faces[0].promotion = Some("Nonexistent General".to_string());
+    let error = PromotionGraph::new(&faces).err().unwrap();
+    assert_eq!(error.face, faces[0].name);
+    assert_eq!(error.promotion, "Nonexistent General");
+}
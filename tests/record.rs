@@ -0,0 +1,193 @@
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+use shogi_piece_values::{
+    assign_value_to_face, faces, simulate_n_par_statistics, simulate_with_rng, trial_rng, Board,
+    MergeError, Setup, SimulationRecord, SimulationStatistics, Uniform,
+};
+
+fn record_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "shogi-piece-values-record-{}-{name}.json",
+        std::process::id()
+    ))
+}
+
+fn record(trials: usize, seed: u64) -> SimulationRecord {
+    let board = Board::preset("chu").unwrap();
+    let statistics = simulate_n_par_statistics(board, &Uniform, trials, seed);
+    SimulationRecord::from_statistics(board, &Uniform, statistics, Some(seed))
+}
+
+fn close(a: &[f64], b: &[f64]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(a, b)| (a - b).abs() <= 1e-9 * a.abs().max(1.0))
+}
+
+#[test]
+fn merged_records_match_one_run_over_both_trial_ranges() {
+    let merged = record(300, 1).merge(record(200, 2)).unwrap();
+
+    let board = Board::preset("chu").unwrap();
+    let mut together = SimulationStatistics::new();
+    for (seed, trials) in [(1, 300), (2, 200)] {
+        for trial in 0..trials {
+            together.push(&simulate_with_rng(
+                board,
+                &Uniform,
+                &mut trial_rng(seed, trial),
+            ));
+        }
+    }
+
+    let statistics = merged.statistics.unwrap();
+    assert_eq!(merged.trials, 500);
+    assert_eq!(statistics.count(), together.count());
+    assert!(close(
+        &statistics.mean().to_fields(),
+        &together.mean().to_fields()
+    ));
+    assert!(close(
+        &statistics.variance().to_fields(),
+        &together.variance().to_fields()
+    ));
+}
+
+#[test]
+fn records_of_different_runs_or_the_same_seed_do_not_merge() {
+    let record = || self::record(8, 1);
+    let other_board = {
+        let board = Board::preset("dai").unwrap();
+        let statistics = simulate_n_par_statistics(board, &Uniform, 8, 2);
+        SimulationRecord::from_statistics(board, &Uniform, statistics, Some(2))
+    };
+    let other_occupancy = {
+        let board = Board::preset("chu").unwrap();
+        let setup = Setup {
+            max_removed: 0.0,
+            array: None,
+        };
+        let statistics = simulate_n_par_statistics(board, &setup, 8, 2);
+        SimulationRecord::from_statistics(board, &setup, statistics, Some(2))
+    };
+
+    assert!(matches!(
+        record().merge(other_board),
+        Err(MergeError::BoardMismatch)
+    ));
+    assert!(matches!(
+        record().merge(other_occupancy),
+        Err(MergeError::OccupancyModelMismatch)
+    ));
+    assert!(matches!(
+        record().merge(record()),
+        Err(MergeError::SameSeed(1))
+    ));
+}
+
+// Saves `record` with `edit` applied to its JSON and loads it back.
+fn reload(
+    record: &SimulationRecord,
+    name: &str,
+    edit: impl FnOnce(&mut serde_json::Value),
+) -> Result<SimulationRecord, String> {
+    let path = record_path(name);
+    record.save(&path).unwrap();
+    let mut json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    edit(&mut json);
+    std::fs::write(&path, json.to_string()).unwrap();
+    let loaded = SimulationRecord::load(&path).map_err(|e| e.to_string());
+    std::fs::remove_file(&path).unwrap();
+    loaded
+}
+
+#[test]
+fn statistics_from_another_field_layout_are_rejected_on_loading() {
+    let record = record(8, 1);
+    let error = reload(&record, "short-mean", |json| {
+        json["statistics"]["mean"].as_array_mut().unwrap().pop();
+    })
+    .unwrap_err();
+    assert!(error.contains("fields"), "{error}");
+
+    let error = reload(&record, "short-comoment", |json| {
+        json["statistics"]["comoment"] = serde_json::json!("AAAA");
+    })
+    .unwrap_err();
+    assert!(error.contains("co-moment"), "{error}");
+}
+
+#[test]
+fn saved_records_load_back_unchanged() {
+    let record = record(16, 5);
+    let loaded = reload(&record, "round-trip", |_| {}).unwrap();
+
+    assert_eq!(loaded.trials, record.trials);
+    assert_eq!(loaded.seed, record.seed);
+    assert_eq!(loaded.board().unwrap(), record.board().unwrap());
+    assert_eq!(loaded.occupancy_model, record.occupancy_model);
+    assert_eq!(loaded.simulation.to_fields(), record.simulation.to_fields());
+    let (statistics, saved) = (loaded.statistics.unwrap(), record.statistics.unwrap());
+    assert_eq!(statistics.count(), saved.count());
+    assert_eq!(statistics.mean().to_fields(), saved.mean().to_fields());
+    assert_eq!(
+        statistics.variance().to_fields(),
+        saved.variance().to_fields()
+    );
+    for face in faces() {
+        assert_eq!(
+            assign_value_to_face(&loaded.simulation, &face),
+            assign_value_to_face(&record.simulation, &face),
+            "{}",
+            face.name
+        );
+    }
+}
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_shogi_piece_values"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn loading_refuses_options_that_disagree_with_the_record() {
+    let path = record_path("cli");
+    let path = path.to_str().unwrap();
+    let saved = run(&[
+        "simulate",
+        "-n",
+        "16",
+        "-s",
+        "3",
+        "--board",
+        "chu",
+        "--no-progress",
+        "--save",
+        path,
+    ]);
+    assert!(saved.status.success());
+
+    assert!(run(&["simulate", "--load", path]).status.success());
+    assert!(run(&["simulate", "--load", path, "--board", "chu"])
+        .status
+        .success());
+    for args in [
+        ["--board", "dai"],
+        ["--variant", "tenjiku"],
+        ["--occupancy", "setup"],
+    ] {
+        let output = run(&[&["simulate", "--load", path][..], &args[..]].concat());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(!output.status.success(), "{args:?}");
+        assert!(
+            stderr.contains("the loaded results were simulated"),
+            "{stderr}"
+        );
+    }
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,9 @@
+use shogi_piece_values::{simulate_n_par_seeded, simulate_n_seeded, Board, Uniform};
+
+#[test]
+fn seeded_serial_and_parallel_runs_agree() {
+    let board = Board::preset("chu").unwrap();
+    let serial = simulate_n_seeded(board, &Uniform, 300, 7);
+    let parallel = simulate_n_par_seeded(board, &Uniform, 300, 7);
+    assert_eq!(serial.to_fields(), parallel.to_fields());
+}
@@ -0,0 +1,49 @@
+use shogi_piece_values::{
+    assign_value_to_face, faces, Convergence, Direction, Simulation, SimulationStatistics,
+};
+
+fn pawns(values: &[f64]) -> SimulationStatistics {
+    let mut statistics = SimulationStatistics::new();
+    for &value in values {
+        let mut sim = Simulation::new();
+        sim.quiet.steps[Direction::North.index()][1] = value;
+        statistics.push(&sim);
+    }
+    statistics
+}
+
+#[test]
+fn exact_multiples_of_the_reference_have_no_error() {
+    let statistics = pawns(&[1.0, 2.0, 3.0, 4.0]);
+    let estimate = statistics.estimate_ratio(|sim| 0.7 * sim.pawn(), Simulation::pawn);
+    assert!((estimate.mean - 0.7).abs() < 1e-12);
+    assert_eq!(estimate.standard_error, 0.0);
+}
+
+fn converged_within(statistics: SimulationStatistics, tolerance: f64) -> bool {
+    let faces = faces();
+    Convergence::assess(
+        statistics,
+        tolerance,
+        &faces,
+        assign_value_to_face,
+        Simulation::pawn,
+    )
+    .converged
+}
+
+fn converged(statistics: SimulationStatistics) -> bool {
+    converged_within(statistics, 0.25)
+}
+
+#[test]
+fn runs_without_spread_converge() {
+    assert!(converged(pawns(&[1.0, 2.0, 3.0, 4.0])));
+    assert!(!converged(pawns(&[1.0])));
+}
+
+#[test]
+fn runs_without_intervals_never_converge() {
+    assert!(!converged_within(pawns(&[1.0]), f64::INFINITY));
+    assert!(!converged_within(pawns(&[0.0, 0.0, 0.0]), f64::INFINITY));
+}
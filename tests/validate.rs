@@ -0,0 +1,85 @@
+use shogi_piece_values::{
+    faces, parse_faces, validate_faces, variants, Direction, Move, Problem, PromotionGraph,
+};
+
+// Problems in the built-in table that are known but not yet resolved. Fixing
+// one means removing it here; anything new makes the test fail.
+const KNOWN_PROBLEMS: &[&str] = &[
+    "Running Boar: name is used by several faces",
+    "獣鳥: kanji shared by Beastly Bird, The Birds",
+    "弓将: kanji shared by Crossbow General, Archery General",
+    "鳩槃: kanji shared by Dove, Pidgeon",
+    "走猪: kanji shared by Running Boar, Running Boar",
+];
+
+#[test]
+fn built_in_faces_have_no_new_problems() {
+    let problems: Vec<String> = validate_faces(&faces())
+        .iter()
+        .map(Problem::to_string)
+        .collect();
+    assert_eq!(problems, KNOWN_PROBLEMS);
+}
+
+#[test]
+fn built_in_variants_have_no_problems() {
+    for variant in variants().into_iter().filter(|v| v.name != "taikyoku") {
+        assert_eq!(validate_faces(&variant.faces), vec![], "{}", variant.name);
+        assert!(
+            PromotionGraph::new(&variant.faces).is_ok(),
+            "{}",
+            variant.name
+        );
+    }
+}
+
+#[test]
+fn broken_faces_are_reported() {
+    let faces = parse_faces(
+        r#"
+        [[faces]]
+        name = "Pawn"
+        kanji = "歩兵"
+        romaji = "fuhyō"
+        promotion = "Gold"
+        moves = [{ Step = [8, "North"] }, { Range = "North" }]
+
+        [[faces]]
+        name = "Pawn"
+        kanji = "歩"
+        romaji = "fu"
+        moves = [{ JumpOrRange = [4, "East"] }, { RangeCapture = "West" }]
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        validate_faces(&faces),
+        vec![
+            Problem::DuplicateName("Pawn".to_string()),
+            Problem::UnknownPromotion {
+                face: "Pawn".to_string(),
+                promotion: "Gold".to_string(),
+            },
+            Problem::StepOutOfRange {
+                face: "Pawn".to_string(),
+                m: Move::Step(8, Direction::North),
+            },
+            Problem::DuplicateMoves {
+                face: "Pawn".to_string(),
+                moves: vec![
+                    Move::Step(8, Direction::North),
+                    Move::Range(Direction::North)
+                ],
+            },
+            Problem::JumpOutOfRange {
+                face: "Pawn".to_string(),
+                m: Move::JumpOrRange(4, Direction::East),
+            },
+            Problem::UnrankedRangeCapture {
+                face: "Pawn".to_string(),
+                m: Move::RangeCapture(Direction::West),
+            },
+        ]
+    );
+}
@@ -0,0 +1,24 @@
+use shogi_piece_values::{variant, variants, PromotionGraph};
+
+#[test]
+fn every_built_in_variant_loads_and_resolves_its_promotions() {
+    let variants = variants();
+    assert!(!variants.is_empty());
+    for built_in in &variants {
+        let graph = PromotionGraph::new(&built_in.faces)
+            .unwrap_or_else(|e| panic!("{}: {e}", built_in.name));
+        for face in &built_in.faces {
+            if let Some(promotion) = &face.promotion {
+                assert_eq!(
+                    graph.promotion(face).map(|p| &p.name),
+                    Some(promotion),
+                    "{}: {}",
+                    built_in.name,
+                    face.name
+                );
+            }
+        }
+        let found = variant(&built_in.name.to_uppercase()).map(|found| found.name);
+        assert_eq!(found.as_ref(), Some(&built_in.name));
+    }
+}
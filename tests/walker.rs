@@ -0,0 +1,204 @@
+use std::fmt::Display;
+
+use rand::RngCore;
+use shogi_piece_values::{
+    faces, simulate_with_rng, starting_array, trial_rng, Board, Grid, Mobility, OccupancyModel,
+    Rank, RankMix, Ranked, Setup, Simulation, Square, Uniform,
+};
+
+const DIRECTIONS: [(i64, i64); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+// Always the same position, so that the walk can be checked square by square.
+struct Fixed(Grid);
+
+impl OccupancyModel for Fixed {
+    fn populate(&self, grid: &mut Grid, _rng: &mut dyn RngCore) {
+        *grid = self.0.clone();
+    }
+}
+
+impl Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "fixed")
+    }
+}
+
+// The squares along a ray, nearest first, stopping at the edge of the inner
+// board that pieces may move to.
+fn walk(grid: &Grid, x: i64, y: i64, (dx, dy): (i64, i64)) -> Vec<Square> {
+    let board = grid.board();
+    let inside = |x: i64, y: i64| {
+        0 < x && x < board.width() as i64 - 1 && 0 < y && y < board.height() as i64 - 1
+    };
+    (1..)
+        .map(|k| (x + k * dx, y + k * dy))
+        .take_while(|&(x, y)| inside(x, y))
+        .map(|(x, y)| grid.get(x as usize, y as usize))
+        .collect()
+}
+
+fn slide(squares: &[Square]) -> (f64, f64) {
+    let mut count = (0.0, 0.0);
+    for square in squares {
+        match square {
+            Square::Empty => count.0 += 1.0,
+            Square::Opponent(_) => return (count.0, count.1 + 1.0),
+            Square::Friendly(_) => break,
+        }
+    }
+    count
+}
+
+fn flying_capture(squares: &[Square]) -> (f64, f64) {
+    let quiet = squares.iter().take_while(|s| **s == Square::Empty).count();
+    let captures = squares
+        .iter()
+        .filter(|s| matches!(s, Square::Opponent(_)))
+        .count();
+    (quiet as f64, captures as f64)
+}
+
+// Up to three pieces can be flown over, and only opponents landed on.
+fn flying_jump(squares: &[Square]) -> (f64, f64) {
+    let mut count = (0.0, 0.0);
+    let mut pieces = 0;
+    for square in squares {
+        match square {
+            Square::Empty => count.0 += 1.0,
+            Square::Opponent(_) => count.1 += 1.0,
+            Square::Friendly(_) => {}
+        }
+        if *square != Square::Empty {
+            pieces += 1;
+            if pieces == 4 {
+                break;
+            }
+        }
+    }
+    count
+}
+
+// Pieces of the mover's own rank or above cannot be flown over.
+fn range_capture(squares: &[Square], rank: Rank) -> (f64, f64) {
+    let mut count = (0.0, 0.0);
+    for square in squares {
+        match square {
+            Square::Empty => count.0 += 1.0,
+            Square::Opponent(other) if *other >= rank => return (count.0, count.1 + 1.0),
+            Square::Friendly(other) if *other >= rank => break,
+            Square::Opponent(_) => count.1 += 1.0,
+            Square::Friendly(_) => {}
+        }
+    }
+    count
+}
+
+fn add(
+    sim: &mut Simulation,
+    field: impl Fn(&mut Mobility) -> &mut f64,
+    (quiet, captures): (f64, f64),
+) {
+    *field(&mut sim.quiet) += quiet;
+    *field(&mut sim.captures) += captures;
+}
+
+fn naive(grid: &Grid) -> Simulation {
+    let board = grid.board();
+    let mut sim = Simulation::new();
+    for x in 0..board.width() as i64 {
+        for y in 0..board.height() as i64 {
+            for (d, &(dx, dy)) in DIRECTIONS.iter().enumerate() {
+                let squares = walk(grid, x, y, (dx, dy));
+                for n in 0..8 {
+                    let count = slide(&squares[..n.min(squares.len())]);
+                    add(&mut sim, |m| &mut m.steps[d][n], count);
+                }
+                add(&mut sim, |m| &mut m.range[d], slide(&squares));
+                add(&mut sim, |m| &mut m.flying_jump[d], flying_jump(&squares));
+                add(
+                    &mut sim,
+                    |m| &mut m.flying_capture[d],
+                    flying_capture(&squares),
+                );
+                for rank in Rank::ALL {
+                    let count = range_capture(&squares, rank);
+                    add(&mut sim, |m| &mut m.range_capture[rank.index()][d], count);
+                }
+
+                let free = squares.iter().take_while(|s| **s == Square::Empty).count() as i64;
+                let mut hook = slide(&squares);
+                for k in 1..=free {
+                    for side in [(-dy, dx), (dy, -dx)] {
+                        let (quiet, captures) = slide(&walk(grid, x + k * dx, y + k * dy, side));
+                        hook = (hook.0 + quiet, hook.1 + captures);
+                    }
+                }
+                add(&mut sim, |m| &mut m.hook[d], hook);
+            }
+        }
+    }
+    sim
+}
+
+fn walked_fields(mobility: &Mobility) -> Vec<f64> {
+    let mut fields: Vec<f64> = mobility.steps.iter().flatten().copied().collect();
+    fields.extend(mobility.range);
+    fields.extend(mobility.flying_jump);
+    fields.extend(mobility.flying_capture);
+    fields.extend(mobility.range_capture.iter().flatten());
+    fields.extend(mobility.hook);
+    fields
+}
+
+fn grids() -> Vec<Grid> {
+    let mut grids = Vec::new();
+    for (board, seed) in [("3x3", 1), ("7x5", 2), ("chu", 3), ("taikyoku", 4)] {
+        let board: Board = board.parse().unwrap();
+        let models: [Box<dyn OccupancyModel>; 2] = [
+            Box::new(Uniform),
+            Box::new(Setup {
+                max_removed: 0.5,
+                array: starting_array(board),
+            }),
+        ];
+        for model in models {
+            let model = Ranked {
+                model,
+                ranks: RankMix::of(&faces()),
+            };
+            let mut grid = Grid::new(board);
+            model.populate(&mut grid, &mut trial_rng(seed, 0));
+            grids.push(grid);
+        }
+        grids.push(Grid::new(board));
+    }
+    grids
+}
+
+#[test]
+fn bitboard_walks_match_square_by_square_walks() {
+    for grid in grids() {
+        let board = grid.board();
+        let walked = simulate_with_rng(board, &Fixed(grid.clone()), &mut trial_rng(0, 0));
+        let expected = naive(&grid);
+        assert_eq!(
+            walked_fields(&walked.quiet),
+            walked_fields(&expected.quiet),
+            "quiet moves on {board}\n{grid}"
+        );
+        assert_eq!(
+            walked_fields(&walked.captures),
+            walked_fields(&expected.captures),
+            "captures on {board}\n{grid}"
+        );
+    }
+}